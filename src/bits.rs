@@ -0,0 +1,50 @@
+use crate::Rand;
+use crate::ordering::{AcqRel, OrderingPolicy};
+
+#[derive(Debug)]
+///Stream of random bits.
+///
+///Buffers single `u64` and hands out its bits one by one, starting from the highest, refilling
+///buffer from [Rand] once all 64 bits are used.
+pub struct BitStream<'a, O = AcqRel> {
+    rand: &'a Rand<O>,
+    bits: u64,
+    remaining: u32,
+}
+
+impl<'a, O: OrderingPolicy> BitStream<'a, O> {
+    #[inline]
+    ///Creates new stream using `rand`.
+    ///
+    ///No value is generated until first bit is requested.
+    pub const fn new(rand: &'a Rand<O>) -> Self {
+        Self {
+            rand,
+            bits: 0,
+            remaining: 0,
+        }
+    }
+
+    #[inline]
+    ///Returns next random bit.
+    pub fn next_bit(&mut self) -> bool {
+        if self.remaining == 0 {
+            self.bits = self.rand.next_u64();
+            self.remaining = u64::BITS;
+        }
+
+        self.remaining -= 1;
+        let bit = self.bits >> 63 == 1;
+        self.bits <<= 1;
+        bit
+    }
+}
+
+impl<O: OrderingPolicy> Iterator for BitStream<'_, O> {
+    type Item = bool;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_bit())
+    }
+}
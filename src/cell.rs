@@ -0,0 +1,103 @@
+use core::cell::Cell;
+
+use crate::{KEY, RandRes, rand32, rand64, bounded_u32, bounded_u64};
+
+#[derive(Debug)]
+///Single threaded representation of algorithm.
+///
+///Same as [Rand](crate::Rand), but counter is stored in `Cell`, avoiding atomic operations.
+///Hence it cannot be shared between threads.
+///
+///In a tight loop generating `u64` it is several times faster than `Rand` on x86_64 (release build),
+///as atomic `fetch_add` is replaced with plain increment.
+pub struct RandCell {
+    counter: Cell<u64>,
+    key: u64,
+}
+
+impl RandCell {
+    #[inline(always)]
+    ///Creates new instance with provided key.
+    pub const fn new(key: u64) -> Self {
+        Self::with_counter(0, key)
+    }
+
+    #[inline]
+    ///Creates new instance with provided key and initial value of counter.
+    pub const fn with_counter(counter: u64, key: u64) -> Self {
+        Self {
+            counter: Cell::new(counter),
+            key,
+        }
+    }
+
+    #[inline]
+    ///Sets new counter value, returning old one
+    pub fn set_counter(&self, counter: u64) -> u64 {
+        self.counter.replace(counter)
+    }
+
+    #[inline]
+    ///Gets current value of counter
+    pub fn counter(&self) -> u64 {
+        self.counter.get()
+    }
+
+    #[inline(always)]
+    fn next_counter(&self) -> u64 {
+        let counter = self.counter.get();
+        self.counter.set(counter.wrapping_add(1));
+        counter
+    }
+
+    #[inline]
+    ///Generates new `u32` together with corresponding counter value
+    pub fn next_full_u32(&self) -> RandRes<u32> {
+        let counter = self.next_counter();
+        RandRes {
+            counter,
+            value: rand32(counter, self.key)
+        }
+    }
+
+    #[inline]
+    ///Generates new `u32`
+    pub fn next_u32(&self) -> u32 {
+        rand32(self.next_counter(), self.key)
+    }
+
+    #[inline]
+    ///Generates new `u32` in range `0..to`
+    pub fn next_u32_up(&self, to: u32) -> u32 {
+        bounded_u32(to, || self.next_u32())
+    }
+
+    #[inline]
+    ///Generates new `u64` together with corresponding counter value
+    pub fn next_full_u64(&self) -> RandRes<u64> {
+        let counter = self.next_counter();
+        RandRes {
+            counter,
+            value: rand64(counter, self.key)
+        }
+    }
+
+    #[inline]
+    ///Generates new `u64`
+    pub fn next_u64(&self) -> u64 {
+        rand64(self.next_counter(), self.key)
+    }
+
+    #[inline]
+    ///Generates new `u64` in range `0..to`
+    pub fn next_u64_up(&self, to: u64) -> u64 {
+        bounded_u64(to, || self.next_u64())
+    }
+}
+
+impl Default for RandCell {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(KEY)
+    }
+}
@@ -0,0 +1,1032 @@
+//!Distributions to sample values from.
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Range, RangeInclusive};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Rand;
+use crate::ordering::{AcqRel, OrderingPolicy};
+use crate::range::SampleUniform;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Distribution construction error.
+pub enum Error {
+    ///Probability is not within `[0, 1]`.
+    InvalidProbability,
+    ///Standard deviation is negative or not finite.
+    InvalidStdDev,
+    ///Rate (lambda) is not positive or not finite.
+    InvalidRate,
+    ///Weights are empty, sum to zero or contain negative or not finite value.
+    InvalidWeights,
+    ///Range is not finite, not ordered or outside of distribution's support.
+    InvalidRange,
+    ///Shape is not positive or not finite.
+    InvalidShape,
+    ///Scale is not positive or not finite.
+    InvalidScale,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidProbability => fmt.write_str("Probability must be within [0, 1]"),
+            Error::InvalidStdDev => fmt.write_str("Standard deviation must be finite and non-negative"),
+            Error::InvalidRate => fmt.write_str("Rate must be finite and positive"),
+            Error::InvalidWeights => fmt.write_str("Weights must be finite, non-negative and have positive sum"),
+            Error::InvalidRange => fmt.write_str("Range must be finite, ordered and within support"),
+            Error::InvalidShape => fmt.write_str("Shape must be finite and positive"),
+            Error::InvalidScale => fmt.write_str("Scale must be finite and positive"),
+        }
+    }
+}
+
+///Distribution, producing values of type `T`.
+pub trait Distribution<T> {
+    ///Samples new value using `rng`.
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> T;
+
+    #[inline(always)]
+    ///Creates infinite iterator, sampling values using `rng`.
+    fn sample_iter<O: OrderingPolicy>(self, rng: &Rand<O>) -> DistIter<'_, Self, T, O> where Self: Sized {
+        DistIter {
+            distr: self,
+            rng,
+            _type: PhantomData,
+        }
+    }
+
+    #[inline]
+    ///Fills `dst` with values sampled using `rng`.
+    ///
+    ///Produces the same values as calling [sample](Self::sample) for each element in order.
+    fn fill<O: OrderingPolicy>(&self, rng: &Rand<O>, dst: &mut [T]) {
+        for value in dst {
+            *value = self.sample(rng);
+        }
+    }
+}
+
+impl<T, D: Distribution<T> + ?Sized> Distribution<T> for &D {
+    #[inline(always)]
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> T {
+        (**self).sample(rng)
+    }
+
+    #[inline(always)]
+    fn fill<O: OrderingPolicy>(&self, rng: &Rand<O>, dst: &mut [T]) {
+        (**self).fill(rng, dst)
+    }
+}
+
+impl<T: SampleUniform> Distribution<T> for Range<T> {
+    #[inline(always)]
+    ///Samples value within range, refer to [Rand::gen_range] for details.
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> T {
+        rng.gen_range(self.clone())
+    }
+}
+
+impl<T: SampleUniform> Distribution<T> for RangeInclusive<T> {
+    #[inline(always)]
+    ///Samples value within range, refer to [Rand::gen_range] for details.
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> T {
+        rng.gen_range(self.clone())
+    }
+}
+
+#[derive(Debug)]
+///Infinite iterator over values of distribution.
+///
+///Created by [Distribution::sample_iter].
+pub struct DistIter<'a, D, T, O = AcqRel> {
+    distr: D,
+    rng: &'a Rand<O>,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<D: Distribution<T>, T, O: OrderingPolicy> Iterator for DistIter<'_, D, T, O> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.distr.sample(self.rng))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Bernoulli distribution, producing `true` with fixed probability.
+///
+///Probability is converted into integer threshold at construction, so sampling requires single
+///`u64`.
+pub struct Bernoulli {
+    threshold: u64,
+}
+
+impl Bernoulli {
+    //Special value to represent `p == 1`, which doesn't fit threshold.
+    const ALWAYS: u64 = u64::MAX;
+    //2^64
+    const SCALE: f64 = 2.0 * (1u64 << 63) as f64;
+
+    ///Creates distribution with probability `p` to produce `true`.
+    ///
+    ///Returns error if `p` is not within `[0, 1]`.
+    pub fn new(p: f64) -> Result<Self, Error> {
+        if (0.0..1.0).contains(&p) {
+            Ok(Self {
+                threshold: (p * Self::SCALE) as u64,
+            })
+        } else if p == 1.0 {
+            Ok(Self {
+                threshold: Self::ALWAYS,
+            })
+        } else {
+            Err(Error::InvalidProbability)
+        }
+    }
+
+    ///Creates distribution with probability `numerator / denominator` to produce `true`.
+    ///
+    ///Returns error if `denominator` is zero or `numerator` is greater than `denominator`.
+    pub fn from_ratio(numerator: u64, denominator: u64) -> Result<Self, Error> {
+        if denominator == 0 || numerator > denominator {
+            Err(Error::InvalidProbability)
+        } else if numerator == denominator {
+            Ok(Self {
+                threshold: Self::ALWAYS,
+            })
+        } else {
+            Ok(Self {
+                threshold: (((numerator as u128) << 64) / denominator as u128) as u64,
+            })
+        }
+    }
+}
+
+impl Distribution<bool> for Bernoulli {
+    #[inline]
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> bool {
+        if self.threshold == Self::ALWAYS {
+            true
+        } else {
+            rng.next_u64() < self.threshold
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+///Binomial distribution, producing number of successes in `n` independent trials.
+///
+///Simulates each trial with [Bernoulli], so sampling takes `O(n)` time, consuming `n` values
+///from `Rand` (unless `p` is `0` or `1`), which makes it only suitable for small `n`.
+pub struct Binomial {
+    n: u64,
+    p: f64,
+    trial: Bernoulli,
+}
+
+impl Binomial {
+    ///Creates distribution with `n` trials and probability of success `p`.
+    ///
+    ///Returns error if `p` is not within `[0, 1]`.
+    pub fn new(n: u64, p: f64) -> Result<Self, Error> {
+        Ok(Self {
+            n,
+            p,
+            trial: Bernoulli::new(p)?,
+        })
+    }
+
+    #[inline(always)]
+    ///Returns number of trials.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    #[inline(always)]
+    ///Returns probability of success.
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Distribution<u64> for Binomial {
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> u64 {
+        if self.p == 0.0 {
+            0
+        } else if self.p == 1.0 {
+            self.n
+        } else {
+            (0..self.n).filter(|_| self.trial.sample(rng)).count() as u64
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+///Normal (Gaussian) distribution.
+///
+///Uses Box–Muller transform, which produces two values out of two `f64` draws.
+///[sample](Distribution::sample) returns only first of them, so each call consumes two values
+///from `Rand` and discards half of the work, while [sample_pair](Self::sample_pair) returns both.
+///
+///Second value is deliberately not cached: cache would require interior mutability, making
+///`Normal` (and distributions built on it, like [Gamma]) `!Sync`, and its output would depend on
+///state of distribution rather than only on counter of `Rand`, so that resetting counter would not
+///replay the same values. Use [sample_pair](Self::sample_pair) when throughput matters.
+///
+///Requires `std` feature.
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+#[cfg(feature = "std")]
+impl Normal {
+    ///Creates distribution with specified `mean` and `std_dev`.
+    ///
+    ///Returns error if `std_dev` is negative or not finite.
+    ///Zero `std_dev` is allowed, making distribution to always return `mean`.
+    pub fn new(mean: f64, std_dev: f64) -> Result<Self, Error> {
+        if std_dev.is_finite() && std_dev >= 0.0 {
+            Ok(Self {
+                mean,
+                std_dev,
+            })
+        } else {
+            Err(Error::InvalidStdDev)
+        }
+    }
+
+    #[inline(always)]
+    ///Returns mean of distribution.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    #[inline(always)]
+    ///Returns standard deviation of distribution.
+    pub fn std_dev(&self) -> f64 {
+        self.std_dev
+    }
+
+    #[inline]
+    ///Samples two independent values using `rng`, consuming two values from it.
+    ///
+    ///First value is the same as would be returned by [sample](Distribution::sample).
+    pub fn sample_pair<O: OrderingPolicy>(&self, rng: &Rand<O>) -> (f64, f64) {
+        //Shift into (0, 1] to avoid ln(0)
+        let radius = (-2.0 * (1.0 - rng.next_f64()).ln()).sqrt();
+        let (sin, cos) = (2.0 * core::f64::consts::PI * rng.next_f64()).sin_cos();
+        (self.mean + self.std_dev * radius * cos, self.mean + self.std_dev * radius * sin)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Distribution<f64> for Normal {
+    #[inline]
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> f64 {
+        self.sample_pair(rng).0
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+///Exponential distribution.
+///
+///Uses inverse CDF `-ln(1 - u) / lambda`, where `u` is within `[0, 1)`, hence logarithm argument is
+///never zero.
+///
+///Requires `std` feature.
+pub struct Exp {
+    lambda: f64,
+}
+
+#[cfg(feature = "std")]
+impl Exp {
+    ///Creates distribution with specified rate `lambda`.
+    ///
+    ///Returns error if `lambda` is not positive or not finite.
+    pub fn new(lambda: f64) -> Result<Self, Error> {
+        if lambda.is_finite() && lambda > 0.0 {
+            Ok(Self {
+                lambda,
+            })
+        } else {
+            Err(Error::InvalidRate)
+        }
+    }
+
+    #[inline(always)]
+    ///Returns rate of distribution.
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+#[cfg(feature = "std")]
+impl Distribution<f64> for Exp {
+    #[inline]
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> f64 {
+        -(1.0 - rng.next_f64()).ln() / self.lambda
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+///Weighted distribution over indexes, using Vose's alias method.
+///
+///Construction takes `O(n)` time and memory, while each sample takes constant time, consuming two
+///values from `Rand`.
+///
+///Requires `alloc` feature.
+pub struct WeightedAliasTable {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl WeightedAliasTable {
+    ///Creates table from `weights`, where probability of each index is proportional to its weight.
+    ///
+    ///Returns error if `weights` is empty, sum of weights is zero or any weight is negative or
+    ///not finite.
+    pub fn new(weights: &[f64]) -> Result<Self, Error> {
+        if weights.iter().any(|weight| !weight.is_finite() || *weight < 0.0) {
+            return Err(Error::InvalidWeights);
+        }
+
+        let total = weights.iter().sum::<f64>();
+        if !(total.is_finite() && total > 0.0) {
+            return Err(Error::InvalidWeights);
+        }
+
+        let len = weights.len();
+        let mut scaled: Vec<f64> = weights.iter().map(|weight| weight * len as f64 / total).collect();
+        let mut prob = alloc::vec![1.0; len];
+        let mut alias: Vec<usize> = (0..len).collect();
+
+        let mut small = Vec::with_capacity(len);
+        let mut large = Vec::with_capacity(len);
+        for (idx, weight) in scaled.iter().enumerate() {
+            if *weight < 1.0 {
+                small.push(idx);
+            } else {
+                large.push(idx);
+            }
+        }
+
+        while let (Some(less), Some(more)) = (small.pop(), large.pop()) {
+            prob[less] = scaled[less];
+            alias[less] = more;
+
+            scaled[more] = (scaled[more] + scaled[less]) - 1.0;
+            if scaled[more] < 1.0 {
+                small.push(more);
+            } else {
+                large.push(more);
+            }
+        }
+        //Whatever remains (including leftovers from rounding errors) has probability 1, which is already set.
+
+        Ok(Self {
+            prob,
+            alias,
+        })
+    }
+
+    #[inline(always)]
+    ///Returns number of weights in table.
+    pub fn len(&self) -> usize {
+        self.prob.len()
+    }
+
+    #[inline(always)]
+    ///Returns whether table is empty, which is never the case.
+    pub fn is_empty(&self) -> bool {
+        self.prob.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Distribution<usize> for WeightedAliasTable {
+    #[inline]
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> usize {
+        let idx = rng.next_u64_up(self.prob.len() as u64) as usize;
+        if rng.next_f64() < self.prob[idx] {
+            idx
+        } else {
+            self.alias[idx]
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+///Triangular distribution within `[min, max]`, peaking at `mode`.
+///
+///Uses inverse CDF on single `f64` draw.
+///
+///Requires `std` feature.
+pub struct Triangular {
+    min: f64,
+    max: f64,
+    mode: f64,
+}
+
+#[cfg(feature = "std")]
+impl Triangular {
+    ///Creates distribution with specified bounds and `mode`.
+    ///
+    ///Returns error if any parameter is not finite or `min <= mode <= max` doesn't hold.
+    pub fn new(min: f64, max: f64, mode: f64) -> Result<Self, Error> {
+        if min.is_finite() && max.is_finite() && mode.is_finite() && min <= mode && mode <= max {
+            Ok(Self {
+                min,
+                max,
+                mode,
+            })
+        } else {
+            Err(Error::InvalidRange)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Distribution<f64> for Triangular {
+    #[inline]
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> f64 {
+        let range = self.max - self.min;
+        if range == 0.0 {
+            return self.min;
+        }
+
+        let value = rng.next_f64();
+        let left = self.mode - self.min;
+        if value * range < left {
+            self.min + (value * range * left).sqrt()
+        } else {
+            self.max - ((1.0 - value) * range * (self.max - self.mode)).sqrt()
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+///Geometric distribution, producing number of failures before first success.
+///
+///Uses `floor(ln(u) / ln(1 - p))`, where `u` is within `(0, 1]`. Denominator is computed as
+///`ln_1p(-p)`, so tiny `p` doesn't round it to zero. Result saturates at `u64::MAX`.
+///
+///Requires `std` feature.
+pub struct Geometric {
+    p: f64,
+}
+
+#[cfg(feature = "std")]
+impl Geometric {
+    ///Creates distribution with probability of success `p`.
+    ///
+    ///Returns error if `p` is not within `(0, 1]`.
+    pub fn new(p: f64) -> Result<Self, Error> {
+        if p > 0.0 && p <= 1.0 {
+            Ok(Self {
+                p,
+            })
+        } else {
+            Err(Error::InvalidProbability)
+        }
+    }
+
+    #[inline(always)]
+    ///Returns probability of success.
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+}
+
+#[cfg(feature = "std")]
+impl Distribution<u64> for Geometric {
+    #[inline]
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> u64 {
+        if self.p == 1.0 {
+            return 0;
+        }
+
+        ((1.0 - rng.next_f64()).ln() / (-self.p).ln_1p()).floor() as u64
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+///Poisson distribution, using Knuth's multiplication method.
+///
+///Each sample takes `O(lambda)` time, consuming on average `lambda + 1` values from `Rand`, so it
+///is only efficient for small `lambda`.
+///To avoid underflow of `exp(-lambda)`, large `lambda` is split into chunks of `500`, summing
+///samples of each chunk.
+///
+///Requires `std` feature.
+pub struct Poisson {
+    lambda: f64,
+    chunks: u64,
+    chunk_limit: f64,
+    rest_limit: f64,
+}
+
+#[cfg(feature = "std")]
+impl Poisson {
+    const CHUNK: f64 = 500.0;
+
+    ///Creates distribution with specified rate `lambda`.
+    ///
+    ///Returns error if `lambda` is not positive or not finite.
+    pub fn new(lambda: f64) -> Result<Self, Error> {
+        if lambda.is_finite() && lambda > 0.0 {
+            let chunks = (lambda / Self::CHUNK).floor();
+            Ok(Self {
+                lambda,
+                chunks: chunks as u64,
+                chunk_limit: (-Self::CHUNK).exp(),
+                rest_limit: (-(lambda - chunks * Self::CHUNK)).exp(),
+            })
+        } else {
+            Err(Error::InvalidRate)
+        }
+    }
+
+    #[inline(always)]
+    ///Returns rate of distribution.
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    fn knuth<O: OrderingPolicy>(limit: f64, rng: &Rand<O>) -> u64 {
+        let mut result = 0;
+        let mut product = rng.next_f64();
+        while product > limit {
+            result += 1;
+            product *= rng.next_f64();
+        }
+        result
+    }
+}
+
+#[cfg(feature = "std")]
+impl Distribution<u64> for Poisson {
+    #[inline]
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> u64 {
+        let mut result = Self::knuth(self.rest_limit, rng);
+        for _ in 0..self.chunks {
+            result += Self::knuth(self.chunk_limit, rng);
+        }
+        result
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+///Gamma distribution, using Marsaglia–Tsang method.
+///
+///Each attempt takes one normal and one uniform draw, with rejection happening rarely.
+///When `shape < 1`, value is sampled with `shape + 1` and boosted by `u^(1 / shape)`
+///using additional uniform draw.
+///
+///Requires `std` feature.
+pub struct Gamma {
+    shape: f64,
+    scale: f64,
+    d: f64,
+    c: f64,
+    normal: Normal,
+}
+
+#[cfg(feature = "std")]
+impl Gamma {
+    ///Creates distribution with specified `shape` and `scale`.
+    ///
+    ///Returns error if `shape` or `scale` is not positive or not finite.
+    pub fn new(shape: f64, scale: f64) -> Result<Self, Error> {
+        if !(shape.is_finite() && shape > 0.0) {
+            return Err(Error::InvalidShape);
+        } else if !(scale.is_finite() && scale > 0.0) {
+            return Err(Error::InvalidScale);
+        }
+
+        let d = if shape < 1.0 {
+            shape + 1.0
+        } else {
+            shape
+        } - 1.0 / 3.0;
+
+        Ok(Self {
+            shape,
+            scale,
+            d,
+            c: 1.0 / (9.0 * d).sqrt(),
+            normal: Normal::new(0.0, 1.0)?,
+        })
+    }
+
+    #[inline(always)]
+    ///Returns shape of distribution.
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    #[inline(always)]
+    ///Returns scale of distribution.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    //Samples `Gamma(d + 1 / 3, 1)`, without boost for `shape < 1`.
+    fn marsaglia_tsang<O: OrderingPolicy>(&self, rng: &Rand<O>) -> f64 {
+        loop {
+            let x = self.normal.sample(rng);
+            let v = 1.0 + self.c * x;
+            if v <= 0.0 {
+                continue;
+            }
+
+            let v = v * v * v;
+            let u = rng.next_f64();
+            let x_sqr = x * x;
+            if u < 1.0 - 0.0331 * x_sqr * x_sqr || u.ln() < 0.5 * x_sqr + self.d * (1.0 - v + v.ln()) {
+                break self.d * v;
+            }
+        }
+    }
+
+    //Samples logarithm of value, consuming the same draws as `sample`.
+    //For small shapes boost `u^(1 / shape)` underflows to zero, while its logarithm stays finite.
+    fn sample_ln<O: OrderingPolicy>(&self, rng: &Rand<O>) -> f64 {
+        let value = self.marsaglia_tsang(rng).ln() + self.scale.ln();
+        if self.shape < 1.0 {
+            value + (1.0 - rng.next_f64()).ln() / self.shape
+        } else {
+            value
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Distribution<f64> for Gamma {
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> f64 {
+        let value = self.marsaglia_tsang(rng);
+
+        if self.shape < 1.0 {
+            value * (1.0 - rng.next_f64()).powf(1.0 / self.shape) * self.scale
+        } else {
+            value * self.scale
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+///Beta distribution, producing values within `[0, 1]`.
+///
+///Sampled as `x / (x + y)`, where `x` and `y` are drawn from `Gamma(alpha, 1)` and
+///`Gamma(beta, 1)` respectively.
+///When either shape is below `1`, both draws may underflow to zero, so they are taken as logarithms
+///instead, producing `1 / (1 + exp(ln(y) - ln(x)))`.
+///
+///Requires `std` feature.
+pub struct Beta {
+    alpha: Gamma,
+    beta: Gamma,
+}
+
+#[cfg(feature = "std")]
+impl Beta {
+    ///Creates distribution with specified shape parameters `alpha` and `beta`.
+    ///
+    ///Returns error if either of parameters is not positive or not finite.
+    pub fn new(alpha: f64, beta: f64) -> Result<Self, Error> {
+        Ok(Self {
+            alpha: Gamma::new(alpha, 1.0)?,
+            beta: Gamma::new(beta, 1.0)?,
+        })
+    }
+
+    #[inline(always)]
+    ///Returns `alpha` shape of distribution.
+    pub fn alpha(&self) -> f64 {
+        self.alpha.shape()
+    }
+
+    #[inline(always)]
+    ///Returns `beta` shape of distribution.
+    pub fn beta(&self) -> f64 {
+        self.beta.shape()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Distribution<f64> for Beta {
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> f64 {
+        if self.alpha.shape() < 1.0 || self.beta.shape() < 1.0 {
+            let ln_x = self.alpha.sample_ln(rng);
+            let ln_y = self.beta.sample_ln(rng);
+            1.0 / (1.0 + (ln_y - ln_x).exp())
+        } else {
+            let x = self.alpha.sample(rng);
+            let y = self.beta.sample(rng);
+            x / (x + y)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+///Log-uniform distribution within `[low, high]`.
+///
+///Logarithm of value is uniformly distributed within `[ln(low), ln(high)]`, so each order of
+///magnitude is equally likely. Requires single `f64` draw.
+///
+///Requires `std` feature.
+pub struct LogUniform {
+    low: f64,
+    high: f64,
+    ln_low: f64,
+    ln_range: f64,
+}
+
+#[cfg(feature = "std")]
+impl LogUniform {
+    ///Creates distribution with specified bounds.
+    ///
+    ///Returns error if any bound is not finite or `0 < low <= high` doesn't hold.
+    pub fn new(low: f64, high: f64) -> Result<Self, Error> {
+        if low.is_finite() && high.is_finite() && low > 0.0 && low <= high {
+            let ln_low = low.ln();
+            Ok(Self {
+                low,
+                high,
+                ln_low,
+                ln_range: high.ln() - ln_low,
+            })
+        } else {
+            Err(Error::InvalidRange)
+        }
+    }
+
+    #[inline(always)]
+    ///Returns lower bound of distribution.
+    pub fn low(&self) -> f64 {
+        self.low
+    }
+
+    #[inline(always)]
+    ///Returns upper bound of distribution.
+    pub fn high(&self) -> f64 {
+        self.high
+    }
+}
+
+#[cfg(feature = "std")]
+impl Distribution<f64> for LogUniform {
+    #[inline]
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> f64 {
+        let value = (self.ln_low + self.ln_range * rng.next_f64()).exp();
+        //Rounding of `exp` may step slightly outside of bounds
+        value.clamp(self.low, self.high)
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+///Cauchy distribution, using inverse CDF `median + scale * tan(pi * (u - 0.5))`.
+///
+///`u` is drawn by [Rand::next_f64_open], so it is never `0` or `1`, where tangent would reach its
+///poles, hence result is always finite. Distribution has heavy tails with undefined mean.
+///
+///Requires `std` feature.
+pub struct Cauchy {
+    median: f64,
+    scale: f64,
+}
+
+#[cfg(feature = "std")]
+impl Cauchy {
+    ///Creates distribution with specified `median` and `scale`.
+    ///
+    ///Returns error if `scale` is not positive or not finite.
+    pub fn new(median: f64, scale: f64) -> Result<Self, Error> {
+        if scale.is_finite() && scale > 0.0 {
+            Ok(Self {
+                median,
+                scale,
+            })
+        } else {
+            Err(Error::InvalidScale)
+        }
+    }
+
+    #[inline(always)]
+    ///Returns median of distribution.
+    pub fn median(&self) -> f64 {
+        self.median
+    }
+
+    #[inline(always)]
+    ///Returns scale of distribution.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+#[cfg(feature = "std")]
+impl Distribution<f64> for Cauchy {
+    #[inline]
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> f64 {
+        let value = rng.next_f64_open();
+        self.median + self.scale * (core::f64::consts::PI * (value - 0.5)).tan()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+///Pareto distribution, using inverse CDF `scale / (1 - u)^(1 / shape)`.
+///
+///`u` is drawn by [Rand::next_f64], so `1 - u` is within `(0, 1]`, never reaching zero.
+///Computed as `scale * exp(-ln(1 - u) / shape)`, as power underflows to zero for small `shape`,
+///while result is clamped to `f64::MAX`, so it is always finite and `>= scale`.
+///
+///Requires `std` feature.
+pub struct Pareto {
+    scale: f64,
+    shape: f64,
+    inv_shape: f64,
+}
+
+#[cfg(feature = "std")]
+impl Pareto {
+    ///Creates distribution with specified `scale` (minimum value) and `shape`.
+    ///
+    ///Returns error if `scale` or `shape` is not positive or not finite.
+    pub fn new(scale: f64, shape: f64) -> Result<Self, Error> {
+        if !(scale.is_finite() && scale > 0.0) {
+            Err(Error::InvalidScale)
+        } else if !(shape.is_finite() && shape > 0.0) {
+            Err(Error::InvalidShape)
+        } else {
+            Ok(Self {
+                scale,
+                shape,
+                inv_shape: 1.0 / shape,
+            })
+        }
+    }
+
+    #[inline(always)]
+    ///Returns scale of distribution.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    #[inline(always)]
+    ///Returns shape of distribution.
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+}
+
+#[cfg(feature = "std")]
+impl Distribution<f64> for Pareto {
+    #[inline]
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> f64 {
+        let value = -(1.0 - rng.next_f64()).ln() * self.inv_shape;
+        (self.scale * value.exp()).min(f64::MAX)
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+///Chi-squared distribution with `freedom` degrees of freedom.
+///
+///Sampled as `Gamma(freedom / 2, 2)`, so both integer and fractional `freedom` are supported:
+///when `freedom < 2`, gamma's shape is below `1` and it uses additional uniform draw to boost value.
+///
+///Requires `std` feature.
+pub struct ChiSquared {
+    freedom: f64,
+    gamma: Gamma,
+}
+
+#[cfg(feature = "std")]
+impl ChiSquared {
+    ///Creates distribution with specified degrees of `freedom`.
+    ///
+    ///Returns [Error::InvalidShape] if `freedom` is not positive or not finite.
+    pub fn new(freedom: f64) -> Result<Self, Error> {
+        Ok(Self {
+            freedom,
+            gamma: Gamma::new(freedom * 0.5, 2.0)?,
+        })
+    }
+
+    #[inline(always)]
+    ///Returns degrees of freedom.
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+}
+
+#[cfg(feature = "std")]
+impl Distribution<f64> for ChiSquared {
+    #[inline]
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> f64 {
+        self.gamma.sample(rng)
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+///Student's t-distribution.
+///
+///Sampled as `z / sqrt(x / freedom)`, where `z` is drawn from standard [Normal] and `x` from
+///[ChiSquared] with `freedom` degrees of freedom.
+///
+///Requires `std` feature.
+pub struct StudentsT {
+    normal: Normal,
+    chi_squared: ChiSquared,
+}
+
+#[cfg(feature = "std")]
+impl StudentsT {
+    ///Creates distribution with specified degrees of `freedom`.
+    ///
+    ///Returns [Error::InvalidShape] if `freedom` is not positive or not finite.
+    pub fn new(freedom: f64) -> Result<Self, Error> {
+        Ok(Self {
+            normal: Normal::new(0.0, 1.0)?,
+            chi_squared: ChiSquared::new(freedom)?,
+        })
+    }
+
+    #[inline(always)]
+    ///Returns degrees of freedom.
+    pub fn freedom(&self) -> f64 {
+        self.chi_squared.freedom()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Distribution<f64> for StudentsT {
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> f64 {
+        let z = self.normal.sample(rng);
+        let x = self.chi_squared.sample(rng);
+        z / (x / self.chi_squared.freedom()).sqrt()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+///Log-normal distribution, sampled as `exp(x)`, where `x` is drawn from `Normal(mu, sigma)`.
+///
+///Requires `std` feature.
+pub struct LogNormal {
+    normal: Normal,
+}
+
+#[cfg(feature = "std")]
+impl LogNormal {
+    ///Creates distribution with specified `mu` and `sigma` of underlying normal distribution.
+    ///
+    ///Returns [Error::InvalidStdDev] if `sigma` is not positive or not finite.
+    pub fn new(mu: f64, sigma: f64) -> Result<Self, Error> {
+        if sigma > 0.0 {
+            Ok(Self {
+                normal: Normal::new(mu, sigma)?,
+            })
+        } else {
+            Err(Error::InvalidStdDev)
+        }
+    }
+
+    #[inline(always)]
+    ///Returns mean of underlying normal distribution.
+    pub fn mu(&self) -> f64 {
+        self.normal.mean()
+    }
+
+    #[inline(always)]
+    ///Returns standard deviation of underlying normal distribution.
+    pub fn sigma(&self) -> f64 {
+        self.normal.std_dev()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Distribution<f64> for LogNormal {
+    #[inline]
+    fn sample<O: OrderingPolicy>(&self, rng: &Rand<O>) -> f64 {
+        self.normal.sample(rng).exp()
+    }
+}
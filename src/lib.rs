@@ -8,74 +8,355 @@
 //!There are several note-worthy properties to the algorithm:
 //!
 //!- State is represented by counter, which is incremented to produce new value, hence making
-//!it easy to predict how state would change.
+//!  it easy to predict how state would change.
 //!- The code is short and simple, only taking minimum amount of operations to produce uniform output.
 //!- `key` must have close to equal number of zeroes and ones for optimal output.
-//!This crate provides single key for use, to have more download key file [gist](https://gist.githubusercontent.com/DoumanAsh/a57bc65434702d5d7fb88343c65f3145/raw/a9b45f7155c483f689318ee501222e72be0d66ec/keys)
+//!  This crate provides single key for use, to have more download key file [gist](https://gist.githubusercontent.com/DoumanAsh/a57bc65434702d5d7fb88343c65f3145/raw/a9b45f7155c483f689318ee501222e72be0d66ec/keys)
+//!
+//!## Reproducibility
+//!
+//!Output of [rand32] and [rand64] depends only on `counter` and `key`, as algorithm uses only
+//!wrapping 64-bit arithmetic, hence it is identical on all targets, regardless of pointer width or
+//!endianness. Functions producing bytes, like [Rand::fill_bytes], always use little-endian order.
+//!
+//!## Period
+//!
+//!Each key produces sequence of `2^64` values, one per counter. Counter silently wraps from
+//!`u64::MAX` back to `0`, after which sequence repeats. Applications that must not reuse counters can
+//!use [Rand::try_next_u32] and [Rand::try_next_u64], which refuse to wrap.
+//!
+//!## Features
+//!
+//!- `alloc` - Enables functionality that requires allocation.
+//!- `std` - Enables functionality that requires standard library, like distributions relying on floating point math and thread-local generator. Implies `alloc`.
+//!- `rand_core` - Implements `RngCore` and `SeedableRng` for [Rand].
+//!- `getrandom` - Enables seeding [Rand] from OS entropy.
+//!- `serde` - Implements `Serialize` and `Deserialize` for [Rand], storing its counter, key and initial counter.
+//!- `rayon` - Enables parallel fill of slices.
+//!- `zeroize` - Implements `Zeroize` for [Rand], wiping its key and counter from memory. Use `zeroize::Zeroizing<Rand>` to wipe on drop.
 
 #![no_std]
 #![warn(missing_docs)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::sync::atomic::{AtomicU64, Ordering};
 
+mod cell;
+pub use cell::RandCell;
+mod bits;
+pub use bits::BitStream;
+pub mod distr;
+pub mod ordering;
+use ordering::{AcqRel, OrderingPolicy};
+pub mod range;
+mod standard;
+pub use standard::Standard;
+#[cfg(feature = "std")]
+mod thread;
+#[cfg(feature = "std")]
+pub use thread::{seed_thread, random_u32, random_u64};
+
 ///Default key to be used with algorithm
 pub const KEY: u64 = 0x5d8491e219f6537d;
 
+#[inline(always)]
+///Returns number of ones in `key`, which should be close to half of its bits (i.e. `32`).
+pub const fn key_balance(key: u64) -> u32 {
+    key.count_ones()
+}
+
 #[inline]
-///Generates random `u32`
+const fn longest_run(mut bits: u64) -> u32 {
+    let mut len = 0;
+    while bits != 0 {
+        bits &= bits << 1;
+        len += 1;
+    }
+    len
+}
+
+//Limits of key_balance for good key.
+const MIN_BALANCE: u32 = 28;
+const MAX_BALANCE: u32 = 36;
+//Maximum length of run of identical bits for good key.
+const MAX_RUN: u32 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Reason for key to be rejected by [Rand::try_new].
 ///
-///- `counter` - Integer counter which acts as state. Should be increased to generate new
-///number.
-///- `key` - Integer which in general should be irregular bit pattern with approximately equal
-///number of zeros and ones. Generally should be constant, but can be changed when new range of
-///random numbers is required.
-pub const fn rand32(counter: u64, key: u64) -> u32 {
+///Limits are described in [is_good_key].
+pub enum BadKey {
+    ///[key_balance] is out of allowed range, contains actual number of ones.
+    Unbalanced(u32),
+    ///Key contains too long run of identical bits, contains length of longest run.
+    LongRun(u32),
+}
+
+impl core::fmt::Display for BadKey {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BadKey::Unbalanced(ones) => write!(fmt, "Key has {} ones, but {}..={} is required", ones, MIN_BALANCE, MAX_BALANCE),
+            BadKey::LongRun(len) => write!(fmt, "Key has run of {} identical bits, but no more than {} is allowed", len, MAX_RUN),
+        }
+    }
+}
+
+const fn check_key(key: u64) -> Result<(), BadKey> {
+    let balance = key_balance(key);
+    if balance < MIN_BALANCE || balance > MAX_BALANCE {
+        return Err(BadKey::Unbalanced(balance));
+    }
+
+    let ones = longest_run(key);
+    let zeroes = longest_run(!key);
+    let run = if ones > zeroes {
+        ones
+    } else {
+        zeroes
+    };
+    if run > MAX_RUN {
+        return Err(BadKey::LongRun(run));
+    }
+
+    Ok(())
+}
+
+///Checks whether `key` is good for use with algorithm.
+///
+///Key is considered good when:
+///
+///- [key_balance] is within `28..=36`;
+///- There is no run of more than `8` identical bits.
+pub const fn is_good_key(key: u64) -> bool {
+    check_key(key).is_ok()
+}
+
+///Returns `key` if it passes [is_good_key], panicking otherwise.
+///
+///Intended for use in `const` context, where panic becomes compilation error, so that custom key
+///is verified at compile time:
+///
+///```rust
+///use squares_rnd::{assert_good_key, KEY};
+///
+///const MY_KEY: u64 = assert_good_key(KEY);
+///```
+///
+///Bad key fails to compile with `evaluation of constant value failed` error:
+///
+///```compile_fail
+///use squares_rnd::assert_good_key;
+///
+///const MY_KEY: u64 = assert_good_key(0);
+///```
+///
+///## Panics
+///
+///When `key` doesn't pass [is_good_key].
+pub const fn assert_good_key(key: u64) -> u64 {
+    assert!(is_good_key(key), "Key must have close to equal number of zeroes and ones, without long runs of identical bits");
+    key
+}
+
+///Generates key, which passes [is_good_key], using `seed`.
+///
+///Candidates are produced by [rand64] with default [KEY], starting with `seed` as counter, until
+///first good key is found. Hence result is deterministic for the same `seed`.
+pub const fn generate_key(seed: u64) -> u64 {
+    let mut counter = seed;
+    loop {
+        let candidate = rand64(counter, KEY);
+        if is_good_key(candidate) {
+            break candidate;
+        }
+        counter = counter.wrapping_add(1);
+    }
+}
+
+#[inline(always)]
+//Four rounds shared by rand32 and rand64, returning state after last round together with `y`.
+//rand32 takes upper half of state, while rand64 only modifies its lower half with fifth round,
+//hence upper 32 bits of rand64 are always equal to rand32.
+const fn rounds(counter: u64, key: u64) -> (u64, u64) {
     let mut x = counter.wrapping_mul(key);
     let y = x;
     let z = y.wrapping_add(key);
 
     x = x.wrapping_mul(x).wrapping_add(y);
-    x = (x >> 32) | (x << 32);
+    x = x.rotate_left(32);
 
     x = x.wrapping_mul(x).wrapping_add(z);
-    x = (x >> 32) | (x << 32);
+    x = x.rotate_left(32);
 
     x = x.wrapping_mul(x).wrapping_add(y);
-    x = (x >> 32) | (x << 32);
+    x = x.rotate_left(32);
 
-    (x.wrapping_mul(x).wrapping_add(z) >> 32) as u32
+    (x.wrapping_mul(x).wrapping_add(z), y)
+}
+
+#[inline]
+///Generates random `u32`
+///
+///- `counter` - Integer counter which acts as state. Should be increased to generate new
+///  number.
+///- `key` - Integer which in general should be irregular bit pattern with approximately equal
+///  number of zeros and ones. Generally should be constant, but can be changed when new range of
+///  random numbers is required.
+pub const fn rand32(counter: u64, key: u64) -> u32 {
+    let (x, _) = rounds(counter, key);
+    (x >> 32) as u32
 }
 
 #[inline]
 ///Generates random `u64`
 ///
 ///- `counter` - Integer counter which acts as state. Should be increased to generate new
-///number.
+///  number.
 ///- `key` - Integer which in general should be irregular bit pattern with approximately equal
-///number of zeros and ones. Generally should be constant, but can be changed when new range of
-///random numbers is required.
+///  number of zeros and ones. Generally should be constant, but can be changed when new range of
+///  random numbers is required.
+///
+///Shares first four rounds with [rand32], adding fifth one that only affects lower 32 bits,
+///hence upper 32 bits are always equal to [rand32] with the same `counter` and `key`.
 pub const fn rand64(counter: u64, key: u64) -> u64 {
-    let mut x = counter.wrapping_mul(key);
-    let y = x;
-    let z = y.wrapping_add(key);
+    let (t, y) = rounds(counter, key);
+    let x = t.rotate_left(32);
 
-    x = x.wrapping_mul(x).wrapping_add(y);
-    x = (x >> 32) | (x << 32);
+    t ^ (x.wrapping_mul(x).wrapping_add(y) >> 32)
+}
 
-    x = x.wrapping_mul(x).wrapping_add(z);
-    x = (x >> 32) | (x << 32);
+#[inline]
+///Generates random `u32`, returning it together with next value of counter.
+///
+///Allows to thread state explicitly, e.g. in `const` context, producing the same sequence as
+///[Rand::next_u32].
+pub const fn next32(counter: u64, key: u64) -> (u32, u64) {
+    (rand32(counter, key), counter.wrapping_add(1))
+}
 
-    x = x.wrapping_mul(x).wrapping_add(y);
-    x = (x >> 32) | (x << 32);
+#[inline]
+///Generates random `u64`, returning it together with next value of counter.
+///
+///Allows to thread state explicitly, e.g. in `const` context, producing the same sequence as
+///[Rand::next_u64].
+pub const fn next64(counter: u64, key: u64) -> (u64, u64) {
+    (rand64(counter, key), counter.wrapping_add(1))
+}
 
-    x = x.wrapping_mul(x).wrapping_add(z);
-    let t = x;
-    x = (x >> 32) | (x << 32);
+#[inline]
+///Generates random `u128`
+///
+///Combines [rand64] of `counter` (high half) and `counter + 1` (low half), hence counter should
+///be increased by `2` to generate new number.
+pub const fn rand128(counter: u64, key: u64) -> u128 {
+    ((rand64(counter, key) as u128) << 64) | rand64(counter.wrapping_add(1), key) as u128
+}
 
-    t ^ (x.wrapping_mul(x).wrapping_add(y) >> 32)
+#[inline(always)]
+///Maps `index` to pseudo-random `u64`, using `key`.
+///
+///This is the same function as [rand64], but intended for use as stateless hash: same `index` and
+///`key` always produce the same output.
+///
+///**NOTE**: Not a cryptographic hash.
+///
+///```rust
+///use squares_rnd::{KEY, hash_u64};
+///
+///let bucket = hash_u64(42, KEY) % 16;
+///assert_eq!(bucket, hash_u64(42, KEY) % 16);
+///assert_ne!(hash_u64(42, KEY), hash_u64(43, KEY));
+///```
+pub const fn hash_u64(index: u64, key: u64) -> u64 {
+    rand64(index, key)
+}
+
+///Generates array of random `u32`, using consecutive counters starting from `start_counter`
+///
+///Being `const`, it can be used to initialize lookup tables at compile time.
+pub const fn rand32_array<const N: usize>(start_counter: u64, key: u64) -> [u32; N] {
+    let mut result = [0; N];
+    let mut idx = 0;
+    while idx < N {
+        result[idx] = rand32(start_counter.wrapping_add(idx as u64), key);
+        idx += 1;
+    }
+    result
+}
+
+///Generates array of random `u64`, using consecutive counters starting from `start_counter`
+///
+///Being `const`, it can be used to initialize lookup tables at compile time.
+pub const fn rand64_array<const N: usize>(start_counter: u64, key: u64) -> [u64; N] {
+    let mut result = [0; N];
+    let mut idx = 0;
+    while idx < N {
+        result[idx] = rand64(start_counter.wrapping_add(idx as u64), key);
+        idx += 1;
+    }
+    result
+}
+
+#[inline(always)]
+fn mul_high_u32(a: u32, b: u32) -> u32 {
+    (((a as u64) * (b as u64)) >> 32) as u32
+}
+
+#[inline(always)]
+fn mul_high_u64(a: u64, b: u64) -> u64 {
+    (((a as u128) * (b as u128)) >> 64) as u64
+}
+
+#[inline(always)]
+fn bounded_u32(to: u32, mut next: impl FnMut() -> u32) -> u32 {
+    //https://lemire.me/blog/2016/06/30/fast-random-shuffling/
+    let mut result = next();
+    let mut hi = mul_high_u32(result, to);
+    let mut lo = result.wrapping_mul(to);
+
+    if lo < to {
+        while lo < (to.wrapping_neg() % to) {
+            result = next();
+            hi = mul_high_u32(result, to);
+            lo = result.wrapping_mul(to);
+        }
+    }
+
+    hi
+}
+
+#[inline(always)]
+fn bounded_u64(to: u64, mut next: impl FnMut() -> u64) -> u64 {
+    //https://lemire.me/blog/2016/06/30/fast-random-shuffling/
+    let mut result = next();
+    let mut hi = mul_high_u64(result, to);
+    let mut lo = result.wrapping_mul(to);
+
+    if lo < to {
+        while lo < (to.wrapping_neg() % to) {
+            result = next();
+            hi = mul_high_u64(result, to);
+            lo = result.wrapping_mul(to);
+        }
+    }
+
+    hi
 }
 
 
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn push_hex(out: &mut alloc::string::String, byte: u8) {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    out.push(DIGITS[(byte >> 4) as usize] as char);
+    out.push(DIGITS[(byte & 0xf) as usize] as char);
+}
+
 ///Full rand result
 pub struct RandRes<T> {
     ///Counter, used to generate `value`
@@ -88,43 +369,341 @@ pub struct RandRes<T> {
 ///Stateful representation of algorithm.
 ///
 ///Increments counter on each generation using the same key.
-pub struct Rand {
+///
+///Memory ordering of counter is selected by `O`, refer to [with_ordering](Rand::with_ordering).
+pub struct Rand<O = AcqRel> {
     counter: AtomicU64,
     key: u64,
+    initial: u64,
+    ordering: core::marker::PhantomData<O>,
 }
 
 impl Rand {
+    ///Number of values, which can be generated before sequence starts to repeat, saturated to `u64`.
+    ///
+    ///Actual period is `2^64` (one value per counter), which doesn't fit `u64`. This is also
+    ///number of values provided by [try_next_u32](Self::try_next_u32) when starting from `0`.
+    pub const PERIOD: u64 = u64::MAX;
+
     #[inline(always)]
     ///Creates new instance with provided key.
     pub const fn new(key: u64) -> Self {
         Self::with_counter(0, key)
     }
 
+    ///Creates new instance with provided key, if it passes [is_good_key].
+    ///
+    ///Returns reason of rejection otherwise, allowing to catch misconfigured key early. Use
+    ///[new](Self::new) to accept any key.
+    pub const fn try_new(key: u64) -> Result<Self, BadKey> {
+        match check_key(key) {
+            Ok(()) => Ok(Self::new(key)),
+            Err(error) => Err(error),
+        }
+    }
+
     #[inline]
     ///Creates new instance with provided key and initial value of counter.
     pub const fn with_counter(counter: u64, key: u64) -> Self {
         Self {
             counter: AtomicU64::new(counter),
             key,
+            initial: counter,
+            ordering: core::marker::PhantomData,
         }
     }
 
+    #[inline]
+    ///Creates new instance with provided key, starting at the beginning of stream `stream`.
+    ///
+    ///Stream id occupies upper 32 bits of counter (i.e. stream starts at `stream << 32`), so
+    ///different streams produce independent sequences out of the same key, as long as each
+    ///stream generates no more than `2^32` values. Consecutive ids are fine for most cases, but when
+    ///stream needs more values, ids should be spaced apart accordingly (e.g. spacing of `4` gives
+    ///each stream `2^34` values). Only lower 32 bits of `stream` are used.
+    pub const fn with_stream(key: u64, stream: u64) -> Self {
+        Self::with_counter(stream << 32, key)
+    }
+
+    #[inline]
+    ///Creates new instance, separating its sequence from other users of `key` by `domain` tag.
+    ///
+    ///Domain is mixed into key used by generator: new key is produced by [generate_key] out of
+    ///[hash_u64] of `domain` with `key`. Hence [key](Self::key) returns derived key, rather than
+    ///`key` itself. As counter to output mapping differs per domain, subsystems sharing the same
+    ///`key`, but using different domains, don't produce the same values even at the same counter
+    ///(e.g. after [set_counter](Self::set_counter) or via [nth_u64](Self::nth_u64)).
+    ///
+    ///Counter starts at `0`.
+    ///
+    ///Domain tag should be constant per subsystem and chosen to be unique, for example name of
+    ///subsystem packed into `u64`: `u64::from_le_bytes(*b"physics\0")`, or its hash.
+    pub const fn with_domain(key: u64, domain: u64) -> Self {
+        Self::new(generate_key(hash_u64(domain, key)))
+    }
+
+    ///Creates new instance with default [KEY], folding `bytes` of any length into initial value of counter.
+    ///
+    ///Folding is done as following:
+    ///
+    ///- State starts with `bytes.len()` as `u64`;
+    ///- Each 8 bytes are read as little-endian `u64` (trailing chunk is padded with zeroes), XORed
+    ///  into state, which is then mixed as `rand64(state, KEY)`;
+    ///- Final state becomes counter.
+    ///
+    ///Hence empty slice produces counter `0`, while result is identical on all platforms.
+    pub fn from_seed_bytes(bytes: &[u8]) -> Self {
+        let mut state = bytes.len() as u64;
+
+        for chunk in bytes.chunks(8) {
+            let mut value = [0u8; 8];
+            value[..chunk.len()].copy_from_slice(chunk);
+            state = rand64(state ^ u64::from_le_bytes(value), KEY);
+        }
+
+        Self::with_counter(state, KEY)
+    }
+
+    #[cfg(feature = "getrandom")]
+    ///Creates new instance with default [KEY], using OS entropy as initial value of counter.
+    ///
+    ///Only starting point is unpredictable, algorithm itself is still not cryptographically
+    ///secure.
+    ///
+    ///## Panics
+    ///
+    ///When OS fails to provide entropy.
+    pub fn from_entropy() -> Self {
+        match getrandom::u64() {
+            Ok(counter) => Self::with_counter(counter, KEY),
+            Err(error) => panic!("Unable to get entropy: {}", error),
+        }
+    }
+}
+
+impl<O: OrderingPolicy> Rand<O> {
+    #[inline]
+    ///Changes memory ordering used to access counter to `P`, one of [ordering] types.
+    ///
+    ///Default is [AcqRel], which makes each generation synchronize with previous ones, so that
+    ///counter can be used to order other operations across threads.
+    ///
+    ///- [Relaxed](ordering::Relaxed) - each call still gets unique counter value, but no
+    ///  happens-before relationship is established. Cheapest option on weakly ordered architectures
+    ///  (e.g. aarch64), suitable if generator is only used to produce random numbers.
+    ///- [AcqRel] - default.
+    ///- [SeqCst](ordering::SeqCst) - additionally provides single total order with other `SeqCst`
+    ///  operations. Rarely needed, and most expensive.
+    ///
+    ///On x86_64 all read-modify-write operations compile to the same instruction, so ordering
+    ///mostly affects compiler optimizations.
+    ///
+    ///Ordering is part of type, rather than state of generator, so it has no runtime cost and is
+    ///not stored by `Serialize`, allowing to restore snapshot into generator with any ordering.
+    pub const fn with_ordering<P: OrderingPolicy>(self) -> Rand<P> {
+        let Self { counter, key, initial, .. } = self;
+        Rand {
+            counter,
+            key,
+            initial,
+            ordering: core::marker::PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    ///Gets memory ordering used to advance counter.
+    pub const fn ordering(&self) -> Ordering {
+        O::ORDERING
+    }
+
+    #[inline(always)]
+    fn advance(&self, n: u64) -> u64 {
+        self.counter.fetch_add(n, O::ORDERING)
+    }
+
+    #[inline(always)]
+    fn try_advance(&self) -> Option<u64> {
+        self.counter.fetch_update(O::ORDERING, O::LOAD, |counter| counter.checked_add(1)).ok()
+    }
+
+    #[inline]
+    ///Replaces key, keeping current value of counter.
+    ///
+    ///Counter is not reset, so generator continues from the same position, but within new range
+    ///of random numbers.
+    pub const fn with_key(mut self, key: u64) -> Self {
+        self.key = key;
+        self
+    }
+
     #[inline]
     ///Sets new counter value, returning old one
     pub fn set_counter(&self, counter: u64) -> u64 {
-        self.counter.swap(counter, Ordering::AcqRel)
+        self.counter.swap(counter, O::ORDERING)
+    }
+
+    #[inline(always)]
+    ///Resets counter to `0`, making generator to replay sequence from the beginning.
+    pub fn reset(&self) {
+        self.reset_to(0)
+    }
+
+    #[inline]
+    ///Resets counter to `counter`.
+    ///
+    ///Same as [set_counter](Self::set_counter), but discards old value.
+    pub fn reset_to(&self, counter: u64) {
+        self.counter.store(counter, O::STORE)
+    }
+
+    #[inline(always)]
+    ///Gets key
+    pub const fn key(&self) -> u64 {
+        self.key
     }
 
     #[inline]
     ///Gets current value of counter
     pub fn counter(&self) -> u64 {
-        self.counter.load(Ordering::Acquire)
+        self.counter.load(O::LOAD)
+    }
+
+    #[inline]
+    ///Returns number of counter values consumed since creation.
+    ///
+    ///Calculated as `counter - initial counter`, wrapping on overflow, hence it is only reliable
+    ///within [PERIOD](Self::PERIOD). Any modification of counter (e.g. [jump](Self::jump) or
+    ///[set_counter](Self::set_counter)) is reflected in result too.
+    ///
+    ///Initial counter is preserved by `Clone` and `serde`, while snapshot without it (made by older
+    ///version) uses restored counter as initial one.
+    pub fn counters_consumed(&self) -> u64 {
+        self.counter().wrapping_sub(self.initial)
+    }
+
+    #[inline]
+    ///Advances counter by `n`, returning old value.
+    ///
+    ///Counter wraps around on overflow, so jumping past `u64::MAX` continues from `0`.
+    ///
+    ///Returned value is start of reserved range `old..old + n`, which can be used to give each
+    ///worker disjoint part of the same sequence via [with_counter](Self::with_counter).
+    pub fn jump(&self, n: u64) -> u64 {
+        self.advance(n)
+    }
+
+    #[inline(always)]
+    ///Discards next `n` values without generating them.
+    ///
+    ///Same as [jump](Self::jump), but without returning old counter.
+    pub fn skip(&self, n: u64) {
+        self.jump(n);
+    }
+
+    #[inline]
+    ///Moves counter forward to `counter`, if it is ahead of current value.
+    ///
+    ///Returns `true` if counter has been moved. Otherwise counter is left unchanged, hence
+    ///it never rewinds generator, which is useful when replaying append-only log.
+    ///Note that wrapping is not considered, so counter cannot be moved past `u64::MAX` by this method.
+    pub fn advance_to(&self, counter: u64) -> bool {
+        self.counter.fetch_max(counter, O::ORDERING) < counter
+    }
+
+    ///Creates `count` independent generators with the same key.
+    ///
+    ///Counter space is divided into `count + 1` equal regions of `u64::MAX / (count + 1)` values,
+    ///starting at current counter. First region is left to `self`, while each child starts at the
+    ///beginning of its own region. Parent's counter is not modified.
+    ///
+    ///Hence sequences do not overlap as long as each generator (including `self`) produces no more
+    ///than region's size of values.
+    pub fn split(&self, count: u64) -> impl Iterator<Item = Rand<O>> {
+        let key = self.key;
+        let base = self.counter();
+        let stride = u64::MAX / count.saturating_add(1);
+
+        (1..=count).map(move |idx| Rand::with_counter(base.wrapping_add(idx.wrapping_mul(stride)), key).with_ordering::<O>())
+    }
+
+    #[inline]
+    ///Returns `u32`, which would be generated next, without advancing counter.
+    ///
+    ///As counter is not modified, concurrent callers may observe the same value.
+    pub fn peek_u32(&self) -> u32 {
+        rand32(self.counter(), self.key)
+    }
+
+    #[inline]
+    ///Returns `u64`, which would be generated next, without advancing counter.
+    ///
+    ///As counter is not modified, concurrent callers may observe the same value.
+    pub fn peek_u64(&self) -> u64 {
+        rand64(self.counter(), self.key)
+    }
+
+    #[inline]
+    ///Returns `u32`, which is generated at specified `counter`, without modifying state.
+    pub fn nth_u32(&self, counter: u64) -> u32 {
+        rand32(counter, self.key)
+    }
+
+    #[inline]
+    ///Returns `u64`, which is generated at specified `counter`, without modifying state.
+    pub fn nth_u64(&self, counter: u64) -> u64 {
+        rand64(counter, self.key)
+    }
+
+    #[inline]
+    ///Returns `u64` bound to 2D coordinates `(x, y)`, without modifying state.
+    ///
+    ///Row `x` is hashed into base counter, to which `y` is added, so that for each row all
+    ///columns map to distinct counters. As it only depends on key, the same tile always gets the
+    ///same value regardless of order in which tiles are visited.
+    ///
+    ///Coordinates are not symmetric: `(x, y)` and `(y, x)` produce different values.
+    pub fn at_coords(&self, x: i64, y: i64) -> u64 {
+        let row = rand64(x as u64, self.key);
+        rand64(row.wrapping_add(y as u64), self.key)
+    }
+
+    #[inline]
+    ///Generates new `u8`, using highest bits of `u32`
+    pub fn next_u8(&self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    #[inline]
+    ///Generates new `u16`, using highest bits of `u32`
+    pub fn next_u16(&self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    #[inline]
+    ///Generates new `usize` in range `0..len`, suitable for indexing.
+    ///
+    ///Uses `u64` on 64-bit platforms and `u32` otherwise.
+    ///
+    ///## Panics
+    ///
+    ///When `len` is zero.
+    pub fn gen_index(&self, len: usize) -> usize {
+        assert!(len != 0, "len must not be zero");
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            self.next_u64_up(len as u64) as usize
+        }
+        #[cfg(not(target_pointer_width = "64"))]
+        {
+            self.next_u32_up(len as u32) as usize
+        }
     }
 
     #[inline]
     ///Generates new `u32` together with corresponding counter value
     pub fn next_full_u32(&self) -> RandRes<u32> {
-        let counter = self.counter.fetch_add(1, Ordering::AcqRel);
+        let counter = self.advance(1);
         RandRes {
             counter,
             value: rand32(counter, self.key)
@@ -134,37 +713,217 @@ impl Rand {
     #[inline]
     ///Generates new `u32`
     pub fn next_u32(&self) -> u32 {
-        rand32(self.counter.fetch_add(1, Ordering::AcqRel), self.key)
+        rand32(self.advance(1), self.key)
+    }
+
+    #[inline]
+    ///Generates new `u32`, unless counter is exhausted.
+    ///
+    ///Returns `None` without modifying counter once it reaches `u64::MAX`, so counter never wraps
+    ///and values are never repeated. As consequence `u64::MAX` itself is never used.
+    pub fn try_next_u32(&self) -> Option<u32> {
+        self.try_advance().map(|counter| rand32(counter, self.key))
+    }
+
+    ///Generates new non-zero `u32`.
+    ///
+    ///Zero is rejected and new value is generated instead, so result is uniformly distributed over
+    ///non-zero values. Zero is rare, hence it almost always takes single counter value.
+    pub fn next_nonzero_u32(&self) -> core::num::NonZeroU32 {
+        loop {
+            if let Some(value) = core::num::NonZeroU32::new(self.next_u32()) {
+                break value;
+            }
+        }
     }
 
     #[inline]
     ///Generates new `u32` in range `0..to`
     pub fn next_u32_up(&self, to: u32) -> u32 {
-        #[inline(always)]
-        fn mul_high_u32(a: u32, b: u32) -> u32 {
-            (((a as u64) * (b as u64)) >> 32) as u32
+        bounded_u32(to, || self.next_u32())
+    }
+
+    #[inline]
+    ///Generates new `u32` in range `0..to`, without rejection.
+    ///
+    ///Uses single multiply-shift of `u32`, always consuming exactly one counter value, so latency
+    ///is constant. The cost is slight bias: some outputs are more likely than others by at most
+    ///`1 / floor(2^32 / to)` relative (i.e. roughly `to / 2^32`), which is negligible for small `to`.
+    ///Use [next_u32_up](Self::next_u32_up) when uniformity matters.
+    ///
+    ///Zero `to` makes range empty, in which case `0` is returned (same as
+    ///[next_u32_up](Self::next_u32_up) does), still consuming one counter value.
+    pub fn next_u32_up_fast(&self, to: u32) -> u32 {
+        mul_high_u32(self.next_u32(), to)
+    }
+
+    #[inline]
+    ///Generates pair of `u32` in range `0..x_to` and `0..y_to`, using single `u64`.
+    ///
+    ///Upper 32 bits of `u64` are mapped onto `0..x_to` and lower 32 bits onto `0..y_to`, each
+    ///via multiply-shift as in [next_u32_up_fast](Self::next_u32_up_fast). Hence exactly one
+    ///counter value is consumed, with the same slight bias of roughly `to / 2^32` per component.
+    ///Use [next_u32_up](Self::next_u32_up) for each component when uniformity matters.
+    ///
+    ///Component with zero bound is always `0`, as in [next_u32_up_fast](Self::next_u32_up_fast).
+    pub fn next_pair_up(&self, x_to: u32, y_to: u32) -> (u32, u32) {
+        let value = self.next_u64();
+        (mul_high_u32((value >> 32) as u32, x_to), mul_high_u32(value as u32, y_to))
+    }
+
+    ///Generates new `u32` in range `0..to`, returning it together with number of rejected draws.
+    ///
+    ///Produces the same value as [next_u32_up](Self::next_u32_up), while number of rejections shows
+    ///how many extra counter values were consumed. Rejection never happens when `to` is power of two.
+    pub fn next_u32_up_stats(&self, to: u32) -> (u32, u32) {
+        let mut rejects = 0;
+        let value = bounded_u32(to, || {
+            rejects += 1;
+            self.next_u32()
+        });
+
+        (value, rejects - 1)
+    }
+
+    #[inline]
+    ///Generates new `u32` in range `low..=high`
+    ///
+    ///## Panics
+    ///
+    ///When `low > high`
+    pub fn next_u32_range(&self, low: u32, high: u32) -> u32 {
+        assert!(low <= high, "low must not be greater than high");
+        match (high - low).checked_add(1) {
+            Some(span) => low + self.next_u32_up(span),
+            //Full range
+            None => self.next_u32(),
         }
+    }
 
-        //https://lemire.me/blog/2016/06/30/fast-random-shuffling/
-        let mut result = self.next_u32();
-        let mut hi = mul_high_u32(result, to);
-        let mut lo = result.wrapping_mul(to);
+    #[inline(always)]
+    ///Generates new value of type `T`.
+    ///
+    ///Supported types are listed in [Standard].
+    ///
+    ///Named `random`, rather than `gen`, as the latter is reserved keyword since edition 2024.
+    pub fn random<T: Standard>(&self) -> T {
+        T::generate(self)
+    }
 
-        if lo < to {
-            while lo < (to.wrapping_neg() % to) {
-                result = self.next_u32();
-                hi = mul_high_u32(result, to);
-                lo = result.wrapping_mul(to);
-            }
+    #[inline]
+    ///Generates new value within `range`, which can be either `low..high` or `low..=high`.
+    ///
+    ///Supported types are listed in [SampleUniform](range::SampleUniform).
+    ///
+    ///## Panics
+    ///
+    ///When `range` is empty.
+    pub fn gen_range<T: range::SampleUniform, R: range::SampleRange<T>>(&self, range: R) -> T {
+        range.sample(self)
+    }
+
+    #[inline]
+    ///Rolls die with `sides`, returning value within `1..=sides`.
+    ///
+    ///## Panics
+    ///
+    ///When `sides` is zero.
+    pub fn roll(&self, sides: u32) -> u32 {
+        assert!(sides != 0, "sides must not be zero");
+        1 + self.next_u32_up(sides)
+    }
+
+    #[inline]
+    ///Rolls `count` dice with `sides`, returning sum of all rolls.
+    ///
+    ///## Panics
+    ///
+    ///- When `sides` is zero.
+    ///- When sum overflows `u32`.
+    pub fn roll_dice(&self, count: u32, sides: u32) -> u32 {
+        assert!(sides != 0, "sides must not be zero");
+        (0..count).fold(0u32, |sum, _| match sum.checked_add(self.roll(sides)) {
+            Some(sum) => sum,
+            None => panic!("Sum of rolls overflows u32"),
+        })
+    }
+
+    #[inline]
+    ///Generates new printable ASCII byte, uniformly distributed within `0x20..=0x7E`
+    ///
+    ///Includes space, but not control characters.
+    pub fn ascii_printable(&self) -> u8 {
+        0x20 + self.next_u32_up(0x7F - 0x20) as u8
+    }
+
+    ///Generates new `char` in range `low..=high`
+    ///
+    ///Surrogate code points `0xD800..=0xDFFF` are skipped, so result is always valid `char`.
+    ///
+    ///## Panics
+    ///
+    ///When `low > high`
+    pub fn gen_char(&self, low: char, high: char) -> char {
+        const SURROGATE_START: u32 = 0xD800;
+        const SURROGATE_LEN: u32 = 0x800;
+
+        assert!(low <= high, "low must not be greater than high");
+        let low = low as u32;
+        let high = high as u32;
+        let gap = if low < SURROGATE_START && high >= SURROGATE_START + SURROGATE_LEN {
+            SURROGATE_LEN
+        } else {
+            0
+        };
+
+        let mut value = self.next_u32_range(low, high - gap);
+        if gap != 0 && value >= SURROGATE_START {
+            value += gap;
         }
 
-        hi
+        match char::from_u32(value) {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    ///Generates new `bool`, using highest bit of `u32`
+    pub fn next_bool(&self) -> bool {
+        self.next_u32() >> 31 == 1
+    }
+
+    #[inline]
+    ///Generates new `bool`, which is `true` with probability `numerator / denominator`
+    ///
+    ///## Panics
+    ///
+    ///- When `denominator` is zero.
+    ///- When `numerator` is greater than `denominator`.
+    pub fn next_bool_ratio(&self, numerator: u32, denominator: u32) -> bool {
+        assert!(denominator != 0, "denominator must not be zero");
+        assert!(numerator <= denominator, "numerator must not be greater than denominator");
+        self.next_u32_up(denominator) < numerator
+    }
+
+    #[inline]
+    ///Generates new `bool`, which is `true` with probability `numerator / denominator`, using only
+    ///integer arithmetic.
+    ///
+    ///If `numerator >= denominator`, always returns `true` without generating value.
+    ///
+    ///## Panics
+    ///
+    ///When `denominator` is zero.
+    pub fn weighted_bool(&self, numerator: u64, denominator: u64) -> bool {
+        assert!(denominator != 0, "denominator must not be zero");
+        numerator >= denominator || self.next_u64_up(denominator) < numerator
     }
 
     #[inline]
     ///Generates new `u64` together with corresponding counter value
     pub fn next_full_u64(&self) -> RandRes<u64> {
-        let counter = self.counter.fetch_add(1, Ordering::AcqRel);
+        let counter = self.advance(1);
         RandRes {
             counter,
             value: rand64(counter, self.key)
@@ -174,31 +933,778 @@ impl Rand {
     #[inline]
     ///Generates new `u64`
     pub fn next_u64(&self) -> u64 {
-        rand64(self.counter.fetch_add(1, Ordering::AcqRel), self.key)
+        rand64(self.advance(1), self.key)
+    }
+
+    #[inline]
+    ///Generates new `u64`, unless counter is exhausted.
+    ///
+    ///Same as [try_next_u32](Self::try_next_u32), but for `u64`.
+    pub fn try_next_u64(&self) -> Option<u64> {
+        self.try_advance().map(|counter| rand64(counter, self.key))
+    }
+
+    ///Generates new non-zero `u64`.
+    ///
+    ///Same as [next_nonzero_u32](Self::next_nonzero_u32), but for `u64`.
+    pub fn next_nonzero_u64(&self) -> core::num::NonZeroU64 {
+        loop {
+            if let Some(value) = core::num::NonZeroU64::new(self.next_u64()) {
+                break value;
+            }
+        }
     }
 
     #[inline]
     ///Generates new `u64` in range `0..to`
     pub fn next_u64_up(&self, to: u64) -> u64 {
-        #[inline(always)]
-        fn mul_high_u64(a: u64, b: u64) -> u64 {
-            (((a as u128) * (b as u128)) >> 64) as u64
+        bounded_u64(to, || self.next_u64())
+    }
+
+    #[inline]
+    ///Generates new `u64` in range `low..=high`
+    ///
+    ///## Panics
+    ///
+    ///When `low > high`
+    pub fn next_u64_range(&self, low: u64, high: u64) -> u64 {
+        assert!(low <= high, "low must not be greater than high");
+        match (high - low).checked_add(1) {
+            Some(span) => low + self.next_u64_up(span),
+            //Full range
+            None => self.next_u64(),
+        }
+    }
+
+    #[inline]
+    ///Generates new `u64` in range `low..=high`, never rejecting and never panicking.
+    ///
+    ///Bounds are swapped if `low > high`. Single `u64` is mapped onto range via multiply-shift,
+    ///so exactly one counter value is consumed, at cost of slight bias: some outputs are more
+    ///likely than others by at most `1 / floor(2^64 / (high - low + 1))` relative.
+    ///Use [next_u64_range](Self::next_u64_range) when uniformity matters.
+    pub fn next_u64_clamped(&self, low: u64, high: u64) -> u64 {
+        let (low, high) = if low > high {
+            (high, low)
+        } else {
+            (low, high)
+        };
+
+        let value = self.next_u64();
+        match (high - low).checked_add(1) {
+            Some(span) => low + mul_high_u64(value, span),
+            //Full range
+            None => value,
+        }
+    }
+
+    #[inline]
+    ///Generates new `u128`, advancing counter by `2`
+    pub fn next_u128(&self) -> u128 {
+        rand128(self.advance(2), self.key)
+    }
+
+    #[inline]
+    ///Generates new `i32`, reinterpreting bits of `u32`
+    pub fn next_i32(&self) -> i32 {
+        self.next_u32() as i32
+    }
+
+    #[inline]
+    ///Generates new `i32` in range `low..high`
+    ///
+    ///## Panics
+    ///
+    ///When `low >= high`
+    pub fn next_i32_range(&self, low: i32, high: i32) -> i32 {
+        assert!(low < high, "low must be less than high");
+        //Difference always fits unsigned type
+        let span = high.wrapping_sub(low) as u32;
+        low.wrapping_add(self.next_u32_up(span) as i32)
+    }
+
+    #[inline]
+    ///Generates new `i64`, reinterpreting bits of `u64`
+    pub fn next_i64(&self) -> i64 {
+        self.next_u64() as i64
+    }
+
+    #[inline]
+    ///Generates new `i64` in range `low..high`
+    ///
+    ///## Panics
+    ///
+    ///When `low >= high`
+    pub fn next_i64_range(&self, low: i64, high: i64) -> i64 {
+        assert!(low < high, "low must be less than high");
+        //Difference always fits unsigned type
+        let span = high.wrapping_sub(low) as u64;
+        low.wrapping_add(self.next_u64_up(span) as i64)
+    }
+
+    #[inline]
+    ///Generates new `f32` in range `[0, 1)`
+    ///
+    ///Uses upper 24 bits of `u32`, dividing it by `2^24`, hence `0.0` is possible while `1.0` is
+    ///never returned.
+    pub fn next_f32(&self) -> f32 {
+        const SCALE: f32 = 1.0 / (1u32 << 24) as f32;
+        (self.next_u32() >> 8) as f32 * SCALE
+    }
+
+    #[inline]
+    ///Generates new `f64` in range `[0, 1)`
+    ///
+    ///Uses upper 53 bits of `u64`, dividing it by `2^53`, hence `0.0` is possible while `1.0` is
+    ///never returned.
+    pub fn next_f64(&self) -> f64 {
+        const SCALE: f64 = 1.0 / (1u64 << 53) as f64;
+        (self.next_u64() >> 11) as f64 * SCALE
+    }
+
+    ///Generates point uniformly distributed inside unit circle.
+    ///
+    ///Uses rejection sampling of points within `[-1, 1)` square, taking `4 / π ≈ 1.27` attempts
+    ///(i.e. pairs of `f64`) on average.
+    pub fn in_unit_circle(&self) -> (f64, f64) {
+        loop {
+            let x = self.next_f64() * 2.0 - 1.0;
+            let y = self.next_f64() * 2.0 - 1.0;
+            if x * x + y * y < 1.0 {
+                break (x, y);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    ///Generates random 2D direction `(cos θ, sin θ)`, where `θ` is within `[0, 2π)`.
+    ///
+    ///Requires `std` feature.
+    pub fn unit_vector_2d(&self) -> (f64, f64) {
+        let (sin, cos) = (self.next_f64() * 2.0 * core::f64::consts::PI).sin_cos();
+        (cos, sin)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    ///Generates random 3D direction, uniformly distributed on unit sphere.
+    ///
+    ///Takes `z` uniformly within `[-1, 1)` and angle `θ` within `[0, 2π)`, resulting in
+    ///`(r cos θ, r sin θ, z)`, where `r = sqrt(1 - z^2)`.
+    ///
+    ///Requires `std` feature.
+    pub fn unit_vector_3d(&self) -> (f64, f64, f64) {
+        let z = self.next_f64() * 2.0 - 1.0;
+        let (x, y) = self.unit_vector_2d();
+        let radius = (1.0 - z * z).sqrt();
+        (radius * x, radius * y, z)
+    }
+
+    #[inline]
+    ///Generates new `f64` in range `(0, 1)`
+    ///
+    ///Uses upper 52 bits of `u64` as `k`, resulting in `(k + 0.5) / 2^52`, which is exactly
+    ///representable, hence result is within `[2^-53, 1 - 2^-53]` and never `0.0` or `1.0`.
+    pub fn next_f64_open(&self) -> f64 {
+        const SCALE: f64 = 1.0 / (1u64 << 52) as f64;
+        ((self.next_u64() >> 12) as f64 + 0.5) * SCALE
+    }
+
+    #[inline]
+    ///Generates new `f64` in range `[low, high)`
+    ///
+    ///Returns `low` when `low == high`.
+    ///
+    ///When difference between `high` and `low` is not representable (e.g. `f64::MIN..f64::MAX`),
+    ///range is scaled by halves, so it never overflows to infinity.
+    ///Note that due to floating point rounding result might be equal to `high`.
+    ///
+    ///## Panics
+    ///
+    ///- When `low > high`.
+    ///- When `low` or `high` is not finite.
+    pub fn next_f64_range(&self, low: f64, high: f64) -> f64 {
+        assert!(low.is_finite() && high.is_finite(), "Range bounds must be finite");
+        assert!(low <= high, "low must not be greater than high");
+
+        let value = self.next_f64();
+        let span = high - low;
+        if span.is_finite() {
+            low + span * value
+        } else {
+            let half = (high * 0.5 - low * 0.5) * value;
+            low + half + half
+        }
+    }
+
+    #[cfg(feature = "std")]
+    ///Generates new duration by multiplying `base` by random factor in range `[1 - factor, 1 + factor)`
+    ///
+    ///Factor is clamped to be non-negative, so with `factor > 1` result can be zero, but never
+    ///negative. Useful to randomize backoff of retries.
+    ///
+    ///Requires `std` feature.
+    ///
+    ///## Panics
+    ///
+    ///- When `factor` is negative or not finite.
+    ///- When result overflows `Duration`.
+    pub fn jitter(&self, base: core::time::Duration, factor: f64) -> core::time::Duration {
+        assert!(factor.is_finite() && factor >= 0.0, "factor must be finite and non-negative");
+
+        let factor = self.next_f64_range(1.0 - factor, 1.0 + factor);
+        base.mul_f64(factor.max(0.0))
+    }
+
+    #[inline]
+    ///Generates array of `u32`, using consecutive counters for its elements
+    pub fn next_u32_array<const N: usize>(&self) -> [u32; N] {
+        rand32_array(self.advance(N as u64), self.key)
+    }
+
+    #[inline]
+    ///Generates array of `u64`, using consecutive counters for its elements
+    pub fn next_u64_array<const N: usize>(&self) -> [u64; N] {
+        rand64_array(self.advance(N as u64), self.key)
+    }
+
+    #[inline]
+    ///Creates infinite iterator over `u32` values, generated by [next_u32](Self::next_u32)
+    pub fn iter_u32(&self) -> impl Iterator<Item = u32> + '_ {
+        core::iter::repeat_with(move || self.next_u32())
+    }
+
+    #[inline]
+    ///Creates infinite iterator over `u64` values, generated by [next_u64](Self::next_u64)
+    pub fn iter_u64(&self) -> impl Iterator<Item = u64> + '_ {
+        core::iter::repeat_with(move || self.next_u64())
+    }
+
+    #[inline]
+    ///Creates infinite iterator over random bytes.
+    ///
+    ///Each `u64` is generated lazily and yields its 8 bytes in little-endian order, producing the
+    ///same bytes as [fill_bytes](Self::fill_bytes).
+    pub fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.iter_u64().flat_map(u64::to_le_bytes)
+    }
+
+    #[inline]
+    ///Creates infinite iterator over `u32` values together with counters used to generate them.
+    pub fn full_iter_u32(&self) -> impl Iterator<Item = RandRes<u32>> + '_ {
+        core::iter::repeat_with(move || self.next_full_u32())
+    }
+
+    #[inline]
+    ///Creates infinite iterator over `u64` values together with counters used to generate them.
+    pub fn full_iter_u64(&self) -> impl Iterator<Item = RandRes<u64>> + '_ {
+        core::iter::repeat_with(move || self.next_full_u64())
+    }
+
+    #[inline(always)]
+    ///Creates [BitStream], generating single random bit at a time.
+    pub fn bit_stream(&self) -> BitStream<'_, O> {
+        BitStream::new(self)
+    }
+
+    #[inline]
+    ///Picks random element from `slice`, returning `None` if it is empty.
+    ///
+    ///Does not require allocation.
+    pub fn choose<'a, T>(&self, slice: &'a [T]) -> Option<&'a T> {
+        match slice.len() {
+            0 => None,
+            len => slice.get(self.next_u64_up(len as u64) as usize),
+        }
+    }
+
+    #[inline]
+    ///Picks random element from `slice`, returning `None` if it is empty.
+    ///
+    ///Does not require allocation.
+    pub fn choose_mut<'a, T>(&self, slice: &'a mut [T]) -> Option<&'a mut T> {
+        match slice.len() {
+            0 => None,
+            len => slice.get_mut(self.next_u64_up(len as u64) as usize),
+        }
+    }
+
+    ///Picks random element from `items`, with probability proportional to its `weight`.
+    ///
+    ///Returns `None` if `items` is empty or total weight is zero.
+    ///`weight` is invoked twice per element, so it must return the same value for the same element.
+    ///
+    ///## Panics
+    ///
+    ///When total weight overflows `u64`.
+    pub fn choose_weighted<'a, T>(&self, items: &'a [T], weight: impl Fn(&T) -> u32) -> Option<&'a T> {
+        let mut total = 0u64;
+        for item in items {
+            total = match total.checked_add(weight(item) as u64) {
+                Some(total) => total,
+                None => panic!("Total weight overflows u64"),
+            };
+        }
+
+        if total == 0 {
+            return None;
+        }
+
+        let mut target = self.next_u64_up(total);
+        for item in items {
+            let weight = weight(item) as u64;
+            if target < weight {
+                return Some(item);
+            }
+            target -= weight;
+        }
+
+        unreachable!()
+    }
+
+    ///Picks random index, using precomputed cumulative sum of weights.
+    ///
+    ///`cumulative` must be sorted ascending, with `cumulative[idx]` being sum of weights up to and
+    ///including `idx`. Index is found by binary search, taking `O(log n)` per draw, which is
+    ///preferable to [choose_weighted](Self::choose_weighted) when the same weights are reused.
+    ///Indexes with zero weight (i.e. equal to previous sum) are never picked.
+    ///
+    ///## Panics
+    ///
+    ///When `cumulative` is empty or its last element is zero.
+    pub fn choose_weighted_index(&self, cumulative: &[u64]) -> usize {
+        let total = match cumulative.last() {
+            Some(total) if *total > 0 => *total,
+            _ => panic!("Cumulative weights must have positive total"),
+        };
+
+        let target = self.next_u64_up(total);
+        cumulative.partition_point(|sum| *sum <= target)
+    }
+
+    #[inline]
+    ///Shuffles `slice` in place using Fisher–Yates algorithm.
+    pub fn shuffle<T>(&self, slice: &mut [T]) {
+        self.partial_shuffle(slice, slice.len())
+    }
+
+    ///Generates random permutation of `0..N` on stack, using Fisher–Yates algorithm.
+    ///
+    ///Does not require allocation. Advances counter by `N - 1` (or `0` for `N <= 1`), producing
+    ///the same result as [shuffle](Self::shuffle) of `[0, 1, ..., N - 1]`.
+    pub fn permutation<const N: usize>(&self) -> [usize; N] {
+        let mut result = core::array::from_fn(|idx| idx);
+        self.shuffle(&mut result);
+        result
+    }
+
+    ///Shuffles `slice` in place, starting from `start_counter`, without modifying state.
+    ///
+    ///Produces the same permutation as [shuffle](Self::shuffle) would with counter set to
+    ///`start_counter`, returning counter value after shuffle, which can be used to continue
+    ///sequence.
+    pub fn shuffle_at<T>(&self, start_counter: u64, slice: &mut [T]) -> u64 {
+        let rand = Rand::with_counter(start_counter, self.key);
+        rand.shuffle(slice);
+        rand.counter()
+    }
+
+    ///Shuffles `slice` in place, randomizing only first `amount` elements.
+    ///
+    ///After completion, first `amount` elements are uniformly sampled from the whole `slice`,
+    ///in random order, while rest of the elements are left in unspecified order.
+    ///If `amount` is greater than length of `slice`, then whole `slice` is shuffled.
+    pub fn partial_shuffle<T>(&self, slice: &mut [T], amount: usize) {
+        let len = slice.len();
+        //Last element has nowhere to go, so no need to waste value on it.
+        let amount = core::cmp::min(amount, len.saturating_sub(1));
+        for idx in 0..amount {
+            let swap_idx = idx + self.next_u64_up((len - idx) as u64) as usize;
+            slice.swap(idx, swap_idx);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    ///Picks `amount` distinct elements from `slice`, in random order.
+    ///
+    ///Uses partial Fisher–Yates shuffle over indexes, taking `O(slice.len())` time and memory.
+    ///If `amount` is not less than length of `slice`, then returns shuffled copy of `slice`.
+    ///
+    ///Requires `alloc` feature.
+    pub fn choose_multiple<T: Clone>(&self, slice: &[T], amount: usize) -> alloc::vec::Vec<T> {
+        let amount = core::cmp::min(amount, slice.len());
+        let mut indexes: alloc::vec::Vec<usize> = (0..slice.len()).collect();
+        self.partial_shuffle(&mut indexes, amount);
+        indexes[..amount].iter().map(|idx| slice[*idx].clone()).collect()
+    }
+
+    #[cfg(feature = "alloc")]
+    ///Picks `amount` distinct indexes within `0..length`, returning them in ascending order.
+    ///
+    ///Uses Floyd's combination algorithm, consuming at least `amount` values (more if bounded
+    ///generation rejects some of them) and never allocating more than `amount` indexes, but taking
+    ///`O(amount^2)` time in worst case, so it is best suited for small `amount`.
+    ///
+    ///Requires `alloc` feature.
+    ///
+    ///## Panics
+    ///
+    ///When `amount > length`
+    pub fn sample_indices(&self, length: usize, amount: usize) -> alloc::vec::Vec<usize> {
+        assert!(amount <= length, "amount must not be greater than length");
+
+        let mut result = alloc::vec::Vec::with_capacity(amount);
+        for idx in (length - amount)..length {
+            let candidate = self.next_u64_up(idx as u64 + 1) as usize;
+            match result.binary_search(&candidate) {
+                //idx is always greater than anything already selected
+                Ok(_) => result.push(idx),
+                Err(pos) => result.insert(pos, candidate),
+            }
+        }
+
+        result
+    }
+
+    #[cfg(feature = "alloc")]
+    ///Picks `amount` elements from `iter` of unknown length, using reservoir sampling (Algorithm R).
+    ///
+    ///Each element has equal probability `amount / n` to be in result, where `n` is number of
+    ///elements in `iter`. If `iter` has no more than `amount` elements, all of them are returned
+    ///in original order.
+    ///
+    ///Requires `alloc` feature.
+    pub fn reservoir_sample<T, I: Iterator<Item = T>>(&self, mut iter: I, amount: usize) -> alloc::vec::Vec<T> {
+        let mut reservoir = alloc::vec::Vec::with_capacity(amount);
+        if amount == 0 {
+            return reservoir;
+        }
+
+        reservoir.extend(iter.by_ref().take(amount));
+        for (idx, item) in iter.enumerate() {
+            let replace_idx = self.next_u64_up((amount + idx + 1) as u64) as usize;
+            if let Some(slot) = reservoir.get_mut(replace_idx) {
+                *slot = item;
+            }
+        }
+
+        reservoir
+    }
+
+    #[cfg(feature = "std")]
+    ///Picks `amount` elements from `iter` of `(item, weight)` pairs, using weighted reservoir
+    ///sampling (A-Res algorithm).
+    ///
+    ///Each item gets key `u^(1 / weight)`, where `u` is drawn by [next_f64_open](Self::next_f64_open),
+    ///and `amount` items with largest keys are returned in unspecified order. Keys are compared as
+    ///`ln(u) / weight` to avoid underflow with small weights.
+    ///
+    ///Weights must be positive: items with zero, negative or not finite weight are skipped without
+    ///consuming counter.
+    ///
+    ///Requires `std` feature.
+    pub fn weighted_reservoir<T, I: Iterator<Item = (T, f64)>>(&self, iter: I, amount: usize) -> alloc::vec::Vec<T> {
+        let mut reservoir: alloc::vec::Vec<(f64, T)> = alloc::vec::Vec::with_capacity(amount);
+        if amount == 0 {
+            return alloc::vec::Vec::new();
+        }
+
+        let mut min_idx = 0;
+        for (item, weight) in iter {
+            if !(weight.is_finite() && weight > 0.0) {
+                continue;
+            }
+
+            let key = self.next_f64_open().ln() / weight;
+            if reservoir.len() < amount {
+                reservoir.push((key, item));
+            } else if key > reservoir[min_idx].0 {
+                reservoir[min_idx] = (key, item);
+            } else {
+                continue;
+            }
+
+            if reservoir.len() == amount {
+                //Only need to find new minimum once reservoir is full
+                min_idx = (1..amount).fold(0, |min, idx| if reservoir[idx].0 < reservoir[min].0 { idx } else { min });
+            }
+        }
+
+        reservoir.into_iter().map(|(_, item)| item).collect()
+    }
+
+    #[cfg(feature = "std")]
+    ///Orders all `items` by weighted sampling without replacement (Efraimidis–Spirakis method).
+    ///
+    ///First item is picked with probability proportional to its weight, second one is picked
+    ///from the rest, proportionally to remaining weights and so on. This is achieved by giving
+    ///each item key `u^(1 / weight)` (compared as `ln(u) / weight`), where `u` is drawn by
+    ///[next_f64_open](Self::next_f64_open), and sorting by keys in descending order, which takes
+    ///`O(n log n)` time and consumes one counter value per item.
+    ///
+    ///Weights must be positive: items with zero, negative or not finite weight don't consume
+    ///counter and are placed last, in original order.
+    ///
+    ///Requires `std` feature.
+    pub fn weighted_permutation<T>(&self, items: alloc::vec::Vec<(T, f64)>) -> alloc::vec::Vec<T> {
+        let mut keyed: alloc::vec::Vec<(f64, T)> = items.into_iter().map(|(item, weight)| {
+            let key = if weight.is_finite() && weight > 0.0 {
+                self.next_f64_open().ln() / weight
+            } else {
+                f64::NEG_INFINITY
+            };
+            (key, item)
+        }).collect();
+
+        keyed.sort_by(|left, right| right.0.total_cmp(&left.0));
+        keyed.into_iter().map(|(_, item)| item).collect()
+    }
+
+    #[cfg(feature = "alloc")]
+    ///Distributes `n` trials across categories with probability proportional to `weights`,
+    ///returning number of trials that fell into each category.
+    ///
+    ///Counts are drawn as sequence of conditional binomials: each category takes share of
+    ///remaining trials with probability `weight / remaining weight`, while last category with
+    ///non-zero weight takes whatever is left. Hence counts always sum to `n`.
+    ///
+    ///Each binomial is simulated trial by trial, consuming one value per remaining trial, so with
+    ///`k` categories total time and number of consumed values are up to `n * (k - 1)`. This makes
+    ///it only suitable for small `n`.
+    ///
+    ///Requires `alloc` feature.
+    ///
+    ///## Panics
+    ///
+    ///When `weights` is empty or sums to zero.
+    pub fn multinomial(&self, n: u64, weights: &[u32]) -> alloc::vec::Vec<u64> {
+        let mut remaining_weight = weights.iter().map(|weight| *weight as u64).sum::<u64>();
+        assert!(remaining_weight > 0, "Weights must have positive sum");
+
+        let mut remaining = n;
+        let mut result = alloc::vec::Vec::with_capacity(weights.len());
+        for weight in weights.iter().map(|weight| *weight as u64) {
+            let count = if remaining == 0 || weight == 0 {
+                0
+            } else if weight == remaining_weight {
+                remaining
+            } else {
+                match distr::Bernoulli::from_ratio(weight, remaining_weight) {
+                    Ok(trial) => (0..remaining).filter(|_| distr::Distribution::sample(&trial, self)).count() as u64,
+                    Err(_) => unreachable!(),
+                }
+            };
+
+            remaining -= count;
+            remaining_weight -= weight;
+            result.push(count);
+        }
+
+        result
+    }
+
+    #[cfg(feature = "alloc")]
+    ///Generates `bytes` random bytes, formatted as lowercase hex string of `2 * bytes` length.
+    ///
+    ///Bytes are the same as produced by [bytes](Self::bytes).
+    ///
+    ///Requires `alloc` feature.
+    pub fn hex_string(&self, bytes: usize) -> alloc::string::String {
+        let mut result = alloc::string::String::with_capacity(bytes * 2);
+        for byte in self.bytes().take(bytes) {
+            push_hex(&mut result, byte);
         }
+        result
+    }
 
-        //https://lemire.me/blog/2016/06/30/fast-random-shuffling/
-        let mut result = self.next_u64();
-        let mut hi = mul_high_u64(result, to);
-        let mut lo = result.wrapping_mul(to);
+    #[cfg(feature = "alloc")]
+    ///Generates string of `len` random characters from `[A-Za-z0-9]`.
+    ///
+    ///Requires `alloc` feature.
+    pub fn alphanumeric_string(&self, len: usize) -> alloc::string::String {
+        const CHARS: &[u8; 62] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+        (0..len).map(|_| CHARS[self.next_u32_up(CHARS.len() as u32) as usize] as char).collect()
+    }
 
-        if lo < to {
-            while lo < (to.wrapping_neg() % to) {
-                result = self.next_u64();
-                hi = mul_high_u64(result, to);
-                lo = result.wrapping_mul(to);
+    #[cfg(feature = "alloc")]
+    ///Generates string of `len` random printable ASCII characters, using [ascii_printable](Self::ascii_printable).
+    ///
+    ///Requires `alloc` feature.
+    pub fn ascii_printable_string(&self, len: usize) -> alloc::string::String {
+        (0..len).map(|_| self.ascii_printable() as char).collect()
+    }
+
+    #[inline]
+    ///Generates random UUID (version 4) bytes, as specified by RFC 4122.
+    ///
+    ///**NOTE**: As generator is not cryptographically secure, resulting UUIDs are predictable and
+    ///must not be used where security is concern.
+    pub fn uuid_v4(&self) -> [u8; 16] {
+        let mut result = [0u8; 16];
+        self.fill_bytes(&mut result);
+        //Version 4
+        result[6] = (result[6] & 0x0f) | 0x40;
+        //Variant 1
+        result[8] = (result[8] & 0x3f) | 0x80;
+        result
+    }
+
+    #[cfg(feature = "alloc")]
+    ///Generates random UUID (version 4) in canonical hyphenated form.
+    ///
+    ///Refer to [uuid_v4](Self::uuid_v4) for details.
+    ///
+    ///Requires `alloc` feature.
+    pub fn uuid_v4_string(&self) -> alloc::string::String {
+        let mut result = alloc::string::String::with_capacity(36);
+        for (idx, byte) in self.uuid_v4().iter().enumerate() {
+            if let 4 | 6 | 8 | 10 = idx {
+                result.push('-');
             }
+            push_hex(&mut result, *byte);
+        }
+        result
+    }
+
+    ///Fills `dst` with random `u32`, advancing counter by `dst.len()`
+    ///
+    ///Produces the same values as consecutive calls to [next_u32](Self::next_u32).
+    pub fn fill_u32(&self, dst: &mut [u32]) {
+        let mut counter = self.advance(dst.len() as u64);
+
+        for value in dst {
+            *value = rand32(counter, self.key);
+            counter = counter.wrapping_add(1);
         }
+    }
+
+    ///Fills `dst` with random `u64`, advancing counter by `dst.len()`
+    ///
+    ///Produces the same values as consecutive calls to [next_u64](Self::next_u64).
+    pub fn fill_u64(&self, dst: &mut [u64]) {
+        let mut counter = self.advance(dst.len() as u64);
 
-        hi
+        for value in dst {
+            *value = rand64(counter, self.key);
+            counter = counter.wrapping_add(1);
+        }
+    }
+
+    ///Invokes `f` with `count` random `u64`, advancing counter by `count`
+    ///
+    ///Produces the same values as consecutive calls to [next_u64](Self::next_u64), but lets
+    ///values to be written into arbitrary sink without intermediate buffer.
+    pub fn generate_u64<F: FnMut(u64)>(&self, count: u64, mut f: F) {
+        let mut counter = self.advance(count);
+
+        for _ in 0..count {
+            f(rand64(counter, self.key));
+            counter = counter.wrapping_add(1);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    ///Fills `dst` with random `u64` in parallel, advancing counter by `dst.len()`
+    ///
+    ///Each chunk of `dst` uses its own part of reserved counter range, so result is the same as
+    ///with [fill_u64](Self::fill_u64).
+    ///
+    ///Requires `rayon` feature.
+    pub fn par_fill_u64(&self, dst: &mut [u64]) {
+        use rayon::prelude::*;
+
+        const CHUNK_SIZE: usize = 4096;
+
+        let counter = self.advance(dst.len() as u64);
+        let key = self.key;
+        dst.par_chunks_mut(CHUNK_SIZE).enumerate().for_each(|(idx, chunk)| {
+            let mut counter = counter.wrapping_add((idx * CHUNK_SIZE) as u64);
+            for value in chunk {
+                *value = rand64(counter, key);
+                counter = counter.wrapping_add(1);
+            }
+        });
+    }
+
+    ///Fills `dst` with random bytes.
+    ///
+    ///Each 8 bytes are taken from single `u64` in little-endian order, so output is the same
+    ///regardless of platform endianness. Trailing chunk, shorter than 8 bytes, takes leading bytes of
+    ///one more `u64`.
+    ///
+    ///Counter is advanced by `ceil(dst.len() / 8)` at once.
+    pub fn fill_bytes(&self, dst: &mut [u8]) {
+        let len = dst.len() as u64;
+        let counter = self.advance(len.div_ceil(8));
+        self.fill_bytes_at(counter, dst);
+    }
+
+    ///Fills `dst` with random bytes, generated starting from `start_counter`, without modifying state.
+    ///
+    ///Produces the same bytes as [fill_bytes](Self::fill_bytes) would with counter set to
+    ///`start_counter`, so the same `start_counter` always expands into the same content.
+    pub fn fill_bytes_at(&self, start_counter: u64, dst: &mut [u8]) {
+        let mut counter = start_counter;
+
+        let mut chunks = dst.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&rand64(counter, self.key).to_le_bytes());
+            counter = counter.wrapping_add(1);
+        }
+
+        let tail = chunks.into_remainder();
+        if !tail.is_empty() {
+            let value = rand64(counter, self.key).to_le_bytes();
+            tail.copy_from_slice(&value[..tail.len()]);
+        }
+    }
+}
+
+impl<O: OrderingPolicy> Clone for Rand<O> {
+    #[inline]
+    ///Creates new instance with the same key and current value of counter.
+    ///
+    ///Clone doesn't share counter with original, so both advance independently.
+    fn clone(&self) -> Self {
+        Self {
+            counter: AtomicU64::new(self.counter()),
+            key: self.key,
+            initial: self.initial,
+            ordering: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<O: OrderingPolicy> PartialEq for Rand<O> {
+    #[inline]
+    ///Compares current values of counter and keys.
+    ///
+    ///As counter can be modified at any time, result only reflects state at the moment of comparison.
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.counter() == other.counter()
+    }
+}
+
+impl<O: OrderingPolicy> Eq for Rand<O> {}
+
+impl<O: OrderingPolicy> core::hash::Hash for Rand<O> {
+    #[inline]
+    ///Hashes current value of counter and key.
+    ///
+    ///As counter can be modified at any time, hash only reflects state at the moment of hashing.
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.counter().hash(state);
+        self.key.hash(state);
+    }
+}
+
+impl From<u64> for Rand {
+    #[inline(always)]
+    ///Creates new instance with provided key.
+    fn from(key: u64) -> Self {
+        Self::new(key)
     }
 }
 
@@ -208,3 +1714,90 @@ impl Default for Rand {
         Self::new(KEY)
     }
 }
+
+#[cfg(feature = "rand_core")]
+impl<O: OrderingPolicy> rand_core::RngCore for Rand<O> {
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        Rand::next_u32(self)
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        Rand::next_u64(self)
+    }
+
+    #[inline(always)]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        Rand::fill_bytes(self, dst)
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl rand_core::SeedableRng for Rand {
+    type Seed = [u8; 8];
+
+    #[inline]
+    ///Creates new instance with default [KEY], using `seed` as little-endian initial value of counter.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::with_counter(u64::from_le_bytes(seed), KEY)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Rand")]
+struct RandState {
+    counter: u64,
+    key: u64,
+    //Missing in snapshots made before it was introduced, in which case counter is used.
+    #[serde(default)]
+    initial: Option<u64>,
+}
+
+#[cfg(feature = "serde")]
+///Stores counter, key and initial counter, used by [counters_consumed](Rand::counters_consumed).
+///
+///Memory ordering, set by [with_ordering](Rand::with_ordering), is part of type and is not stored,
+///so snapshot can be deserialized into generator with any ordering.
+impl<O: OrderingPolicy> serde::Serialize for Rand<O> {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RandState {
+            counter: self.counter(),
+            key: self.key,
+            initial: Some(self.initial),
+        }.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, O: OrderingPolicy> serde::Deserialize<'de> for Rand<O> {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        RandState::deserialize(deserializer).map(|state| {
+            let mut result = Rand::with_counter(state.counter, state.key).with_ordering::<O>();
+            if let Some(initial) = state.initial {
+                result.initial = initial;
+            }
+            result
+        })
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<O: OrderingPolicy> zeroize::Zeroize for Rand<O> {
+    #[inline]
+    ///Overwrites key and counter with zeroes.
+    ///
+    ///**NOTE**: This only wipes state from memory, it doesn't make algorithm cryptographically
+    ///secure.
+    ///
+    ///`Rand` is not wiped on drop, as `Drop` implementation would prevent its use in `const`
+    ///context. Wrap it into `zeroize::Zeroizing` to wipe it on drop.
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+        self.counter.get_mut().zeroize();
+        self.initial.zeroize();
+    }
+}
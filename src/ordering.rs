@@ -0,0 +1,58 @@
+//!Memory orderings of counter, selected via type parameter of [Rand](crate::Rand).
+//!
+//!Ordering is part of type, hence there is no runtime cost of selecting it and default
+//![AcqRel] is the same as hardcoded ordering.
+
+use core::sync::atomic::Ordering;
+
+mod private {
+    pub trait Sealed {}
+}
+
+///Memory ordering, used by [Rand](crate::Rand) to access its counter.
+///
+///Implemented for [Relaxed], [AcqRel] and [SeqCst].
+pub trait OrderingPolicy: private::Sealed {
+    ///Ordering of read-modify-write operations, like advancing counter.
+    const ORDERING: Ordering;
+    ///Ordering of loading counter.
+    const LOAD: Ordering;
+    ///Ordering of storing counter.
+    const STORE: Ordering;
+}
+
+macro_rules! impl_ordering {
+    ($($(#[$doc:meta])* $name:ident => $ordering:ident, $load:ident, $store:ident;)+) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+            $(#[$doc])*
+            pub struct $name;
+
+            impl private::Sealed for $name {}
+            impl OrderingPolicy for $name {
+                const ORDERING: Ordering = Ordering::$ordering;
+                const LOAD: Ordering = Ordering::$load;
+                const STORE: Ordering = Ordering::$store;
+            }
+        )+
+    };
+}
+
+impl_ordering!(
+    ///`Relaxed` ordering.
+    ///
+    ///Each call still gets unique counter value, but no happens-before relationship is established.
+    ///Cheapest option on weakly ordered architectures (e.g. aarch64), suitable if generator is only
+    ///used to produce random numbers.
+    Relaxed => Relaxed, Relaxed, Relaxed;
+    ///`AcqRel` ordering, used by default.
+    ///
+    ///Each generation synchronizes with previous ones, so that counter can be used to order other
+    ///operations across threads.
+    AcqRel => AcqRel, Acquire, Release;
+    ///`SeqCst` ordering.
+    ///
+    ///Additionally provides single total order with other `SeqCst` operations. Rarely needed, and
+    ///most expensive.
+    SeqCst => SeqCst, SeqCst, SeqCst;
+);
@@ -0,0 +1,99 @@
+//!Range sampling support for [Rand::gen_range](crate::Rand::gen_range).
+
+use core::ops::{Range, RangeInclusive};
+
+use crate::Rand;
+use crate::ordering::OrderingPolicy;
+
+mod private {
+    pub trait Sealed {}
+}
+
+///Type, which can be sampled uniformly within range.
+///
+///Implemented for `u32`, `u64`, `i32` and `i64`.
+pub trait SampleUniform: Copy + PartialOrd + private::Sealed {
+    #[doc(hidden)]
+    fn sample_exclusive<O: OrderingPolicy>(rng: &Rand<O>, low: Self, high: Self) -> Self;
+    #[doc(hidden)]
+    fn sample_inclusive<O: OrderingPolicy>(rng: &Rand<O>, low: Self, high: Self) -> Self;
+}
+
+impl private::Sealed for u32 {}
+impl SampleUniform for u32 {
+    #[inline(always)]
+    fn sample_exclusive<O: OrderingPolicy>(rng: &Rand<O>, low: Self, high: Self) -> Self {
+        low + rng.next_u32_up(high - low)
+    }
+
+    #[inline(always)]
+    fn sample_inclusive<O: OrderingPolicy>(rng: &Rand<O>, low: Self, high: Self) -> Self {
+        rng.next_u32_range(low, high)
+    }
+}
+
+impl private::Sealed for u64 {}
+impl SampleUniform for u64 {
+    #[inline(always)]
+    fn sample_exclusive<O: OrderingPolicy>(rng: &Rand<O>, low: Self, high: Self) -> Self {
+        low + rng.next_u64_up(high - low)
+    }
+
+    #[inline(always)]
+    fn sample_inclusive<O: OrderingPolicy>(rng: &Rand<O>, low: Self, high: Self) -> Self {
+        rng.next_u64_range(low, high)
+    }
+}
+
+impl private::Sealed for i32 {}
+impl SampleUniform for i32 {
+    #[inline(always)]
+    fn sample_exclusive<O: OrderingPolicy>(rng: &Rand<O>, low: Self, high: Self) -> Self {
+        rng.next_i32_range(low, high)
+    }
+
+    #[inline(always)]
+    fn sample_inclusive<O: OrderingPolicy>(rng: &Rand<O>, low: Self, high: Self) -> Self {
+        low.wrapping_add(rng.next_u32_range(0, high.wrapping_sub(low) as u32) as i32)
+    }
+}
+
+impl private::Sealed for i64 {}
+impl SampleUniform for i64 {
+    #[inline(always)]
+    fn sample_exclusive<O: OrderingPolicy>(rng: &Rand<O>, low: Self, high: Self) -> Self {
+        rng.next_i64_range(low, high)
+    }
+
+    #[inline(always)]
+    fn sample_inclusive<O: OrderingPolicy>(rng: &Rand<O>, low: Self, high: Self) -> Self {
+        low.wrapping_add(rng.next_u64_range(0, high.wrapping_sub(low) as u64) as i64)
+    }
+}
+
+///Range, which can be sampled by [Rand::gen_range](crate::Rand::gen_range).
+///
+///Implemented for `Range` and `RangeInclusive` of [SampleUniform] types.
+pub trait SampleRange<T: SampleUniform>: private::Sealed {
+    #[doc(hidden)]
+    fn sample<O: OrderingPolicy>(self, rng: &Rand<O>) -> T;
+}
+
+impl<T: SampleUniform> private::Sealed for Range<T> {}
+impl<T: SampleUniform> SampleRange<T> for Range<T> {
+    #[inline(always)]
+    fn sample<O: OrderingPolicy>(self, rng: &Rand<O>) -> T {
+        assert!(self.start < self.end, "Cannot sample empty range");
+        T::sample_exclusive(rng, self.start, self.end)
+    }
+}
+
+impl<T: SampleUniform> private::Sealed for RangeInclusive<T> {}
+impl<T: SampleUniform> SampleRange<T> for RangeInclusive<T> {
+    #[inline(always)]
+    fn sample<O: OrderingPolicy>(self, rng: &Rand<O>) -> T {
+        let (start, end) = self.into_inner();
+        assert!(start <= end, "Cannot sample empty range");
+        T::sample_inclusive(rng, start, end)
+    }
+}
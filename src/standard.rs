@@ -0,0 +1,52 @@
+use crate::Rand;
+use crate::ordering::OrderingPolicy;
+
+mod private {
+    pub trait Sealed {}
+}
+
+///Type, which can be generated with full range by [Rand::random](crate::Rand::random).
+///
+///Integers are generated over their whole range, `bool` is equally likely `true` or `false`,
+///while floats are within `[0, 1)`.
+pub trait Standard: private::Sealed + Sized {
+    #[doc(hidden)]
+    fn generate<O: OrderingPolicy>(rng: &Rand<O>) -> Self;
+}
+
+macro_rules! impl_standard {
+    ($($typ:ty => $method:ident,)+) => {
+        $(
+            impl private::Sealed for $typ {}
+            impl Standard for $typ {
+                #[inline(always)]
+                fn generate<O: OrderingPolicy>(rng: &Rand<O>) -> Self {
+                    rng.$method() as $typ
+                }
+            }
+        )+
+    };
+}
+
+impl_standard!(
+    u8 => next_u8,
+    u16 => next_u16,
+    u32 => next_u32,
+    u64 => next_u64,
+    u128 => next_u128,
+    i8 => next_u8,
+    i16 => next_u16,
+    i32 => next_u32,
+    i64 => next_u64,
+    i128 => next_u128,
+    f32 => next_f32,
+    f64 => next_f64,
+);
+
+impl private::Sealed for bool {}
+impl Standard for bool {
+    #[inline(always)]
+    fn generate<O: OrderingPolicy>(rng: &Rand<O>) -> Self {
+        rng.next_bool()
+    }
+}
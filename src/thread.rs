@@ -0,0 +1,40 @@
+use core::cell::RefCell;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+use crate::{KEY, RandCell};
+
+std::thread_local! {
+    static RAND: RefCell<RandCell> = RefCell::new(RandCell::with_counter(initial_counter(), KEY));
+}
+
+//Hasher keys are randomly initialized by std, providing unpredictable starting point.
+fn initial_counter() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+///Seeds generator of current thread with provided `counter` and `key`.
+///
+///Thread's generator is lazily initialized with random counter and default [KEY], so this
+///function should be used to get reproducible sequence.
+///
+///Requires `std` feature.
+pub fn seed_thread(counter: u64, key: u64) {
+    RAND.with(|rand| *rand.borrow_mut() = RandCell::with_counter(counter, key))
+}
+
+#[inline]
+///Generates new `u32` using generator of current thread.
+///
+///Requires `std` feature.
+pub fn random_u32() -> u32 {
+    RAND.with(|rand| rand.borrow().next_u32())
+}
+
+#[inline]
+///Generates new `u64` using generator of current thread.
+///
+///Requires `std` feature.
+pub fn random_u64() -> u64 {
+    RAND.with(|rand| rand.borrow().next_u64())
+}
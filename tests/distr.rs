@@ -0,0 +1,533 @@
+use squares_rnd::{KEY, Rand};
+use squares_rnd::distr::{self, Bernoulli, Distribution};
+
+const SAMPLES: usize = 100_000;
+
+#[test]
+fn verify_bernoulli() {
+    let rand = Rand::new(KEY);
+
+    let never = Bernoulli::new(0.0).unwrap();
+    assert!((0..SAMPLES).all(|_| !never.sample(&rand)));
+
+    let always = Bernoulli::new(1.0).unwrap();
+    assert!((0..SAMPLES).all(|_| always.sample(&rand)));
+    assert_eq!(always, Bernoulli::from_ratio(3, 3).unwrap());
+
+    let half = Bernoulli::new(0.5).unwrap();
+    assert_eq!(half, Bernoulli::from_ratio(1, 2).unwrap());
+    let count = (0..SAMPLES).filter(|_| half.sample(&rand)).count();
+    let ratio = count as f64 / SAMPLES as f64;
+    assert!((ratio - 0.5).abs() < 0.01, "ratio={}", ratio);
+
+    let third = Bernoulli::from_ratio(1, 3).unwrap();
+    let count = (0..SAMPLES).filter(|_| third.sample(&rand)).count();
+    let ratio = count as f64 / SAMPLES as f64;
+    assert!((ratio - 1.0 / 3.0).abs() < 0.01, "ratio={}", ratio);
+
+    assert_eq!(Bernoulli::new(-0.1), Err(distr::Error::InvalidProbability));
+    assert_eq!(Bernoulli::new(1.1), Err(distr::Error::InvalidProbability));
+    assert_eq!(Bernoulli::new(f64::NAN), Err(distr::Error::InvalidProbability));
+    assert_eq!(Bernoulli::from_ratio(1, 0), Err(distr::Error::InvalidProbability));
+    assert_eq!(Bernoulli::from_ratio(2, 1), Err(distr::Error::InvalidProbability));
+}
+
+#[cfg(feature = "std")]
+fn mean_variance(samples: &[f64]) -> (f64, f64) {
+    let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+    let variance = samples.iter().map(|value| (value - mean) * (value - mean)).sum::<f64>() / samples.len() as f64;
+    (mean, variance)
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_normal() {
+    use distr::Normal;
+
+    let rand = Rand::new(KEY);
+    let normal = Normal::new(5.0, 2.0).unwrap();
+    let samples: Vec<f64> = (0..SAMPLES).map(|_| normal.sample(&rand)).collect();
+    assert_eq!(rand.counter(), 2 * SAMPLES as u64);
+
+    let (mean, variance) = mean_variance(&samples);
+    assert!((mean - 5.0).abs() < 0.05, "mean={}", mean);
+    assert!((variance - 4.0).abs() < 0.1, "variance={}", variance);
+
+    //Output depends only on counter
+    rand.reset();
+    let replay: Vec<f64> = (0..3).map(|_| normal.sample(&rand)).collect();
+    assert_eq!(replay, samples[..3]);
+
+    let other = Rand::new(KEY);
+    other.skip(2);
+    assert_eq!(normal.sample(&other), samples[1]);
+    assert_eq!(other.counter(), 4);
+
+    rand.reset();
+    let pairs: Vec<f64> = (0..SAMPLES / 2).flat_map(|_| {
+        let (first, second) = normal.sample_pair(&rand);
+        [first, second]
+    }).collect();
+    assert_eq!(rand.counter(), SAMPLES as u64);
+    assert_eq!(pairs[0], samples[0]);
+    let (mean, variance) = mean_variance(&pairs);
+    assert!((mean - 5.0).abs() < 0.05, "mean={}", mean);
+    assert!((variance - 4.0).abs() < 0.1, "variance={}", variance);
+
+    let constant = Normal::new(1.0, 0.0).unwrap();
+    assert_eq!(constant.sample(&rand), 1.0);
+
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<Normal>();
+    assert_sync::<distr::Gamma>();
+    assert_sync::<distr::LogNormal>();
+    assert_sync::<distr::StudentsT>();
+
+    assert_eq!(Normal::new(0.0, -1.0).unwrap_err(), distr::Error::InvalidStdDev);
+    assert_eq!(Normal::new(0.0, f64::NAN).unwrap_err(), distr::Error::InvalidStdDev);
+    assert_eq!(Normal::new(0.0, f64::INFINITY).unwrap_err(), distr::Error::InvalidStdDev);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_exp() {
+    use distr::Exp;
+
+    let rand = Rand::new(KEY);
+    let exp = Exp::new(4.0).unwrap();
+    let samples: Vec<f64> = (0..SAMPLES).map(|_| exp.sample(&rand)).collect();
+    assert!(samples.iter().all(|value| *value >= 0.0 && value.is_finite()));
+
+    let (mean, _) = mean_variance(&samples);
+    assert!((mean - 0.25).abs() < 0.005, "mean={}", mean);
+
+    assert_eq!(Exp::new(0.0).unwrap_err(), distr::Error::InvalidRate);
+    assert_eq!(Exp::new(-1.0).unwrap_err(), distr::Error::InvalidRate);
+    assert_eq!(Exp::new(f64::NAN).unwrap_err(), distr::Error::InvalidRate);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn verify_weighted_alias_table() {
+    use distr::WeightedAliasTable;
+
+    let rand = Rand::new(KEY);
+    let weights = [1.0, 0.0, 3.0, 6.0, 0.5];
+    let total = weights.iter().sum::<f64>();
+    let table = WeightedAliasTable::new(&weights).unwrap();
+    assert_eq!(table.len(), weights.len());
+
+    let mut counts = [0usize; 5];
+    for _ in 0..SAMPLES {
+        counts[table.sample(&rand)] += 1;
+    }
+    assert_eq!(counts[1], 0);
+    for (weight, count) in weights.iter().zip(counts.iter()) {
+        let expected = weight / total;
+        let actual = *count as f64 / SAMPLES as f64;
+        assert!((expected - actual).abs() < 0.01, "weight={} actual={}", weight, actual);
+    }
+
+    let single = WeightedAliasTable::new(&[2.5]).unwrap();
+    assert!((0..1000).all(|_| single.sample(&rand) == 0));
+
+    assert_eq!(WeightedAliasTable::new(&[]).unwrap_err(), distr::Error::InvalidWeights);
+    assert_eq!(WeightedAliasTable::new(&[0.0, 0.0]).unwrap_err(), distr::Error::InvalidWeights);
+    assert_eq!(WeightedAliasTable::new(&[1.0, -1.0]).unwrap_err(), distr::Error::InvalidWeights);
+    assert_eq!(WeightedAliasTable::new(&[1.0, f64::NAN]).unwrap_err(), distr::Error::InvalidWeights);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_triangular() {
+    use distr::Triangular;
+
+    let rand = Rand::new(KEY);
+    for (min, max, mode) in [(0.0, 1.0, 0.5), (-2.0, 10.0, 8.0), (1.0, 5.0, 1.0), (1.0, 5.0, 5.0)].iter().copied() {
+        let triangular = Triangular::new(min, max, mode).unwrap();
+        let samples: Vec<f64> = (0..SAMPLES).map(|_| triangular.sample(&rand)).collect();
+        assert!(samples.iter().all(|value| (min..=max).contains(value)));
+
+        let (mean, _) = mean_variance(&samples);
+        let expected = (min + max + mode) / 3.0;
+        assert!((mean - expected).abs() < 0.02 * (max - min), "mean={} expected={}", mean, expected);
+    }
+
+    let constant = Triangular::new(2.0, 2.0, 2.0).unwrap();
+    assert_eq!(constant.sample(&rand), 2.0);
+
+    assert_eq!(Triangular::new(0.0, 1.0, 2.0).unwrap_err(), distr::Error::InvalidRange);
+    assert_eq!(Triangular::new(1.0, 0.0, 0.5).unwrap_err(), distr::Error::InvalidRange);
+    assert_eq!(Triangular::new(0.0, f64::INFINITY, 0.5).unwrap_err(), distr::Error::InvalidRange);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_geometric() {
+    use distr::Geometric;
+
+    let rand = Rand::new(KEY);
+    for p in [0.1, 0.25, 0.5, 0.9].iter().copied() {
+        let geometric = Geometric::new(p).unwrap();
+        let samples: Vec<f64> = (0..SAMPLES).map(|_| geometric.sample(&rand) as f64).collect();
+        let (mean, _) = mean_variance(&samples);
+        let expected = (1.0 - p) / p;
+        assert!((mean - expected).abs() < 0.02 * expected.max(1.0), "p={} mean={}", p, mean);
+    }
+
+    //ln(1 - p) would round to zero
+    let rare = Geometric::new(1e-12).unwrap();
+    let samples: Vec<f64> = (0..SAMPLES).map(|_| rare.sample(&rand) as f64).collect();
+    let (mean, _) = mean_variance(&samples);
+    assert!((mean / 1e12 - 1.0).abs() < 0.02, "mean={}", mean);
+    let never = Geometric::new(1e-300).unwrap();
+    assert!((0..1000).all(|_| never.sample(&rand) == u64::MAX));
+
+    let always = Geometric::new(1.0).unwrap();
+    assert!((0..1000).all(|_| always.sample(&rand) == 0));
+
+    assert_eq!(Geometric::new(0.0).unwrap_err(), distr::Error::InvalidProbability);
+    assert_eq!(Geometric::new(-0.5).unwrap_err(), distr::Error::InvalidProbability);
+    assert_eq!(Geometric::new(1.5).unwrap_err(), distr::Error::InvalidProbability);
+    assert_eq!(Geometric::new(f64::NAN).unwrap_err(), distr::Error::InvalidProbability);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_poisson() {
+    use distr::Poisson;
+
+    let rand = Rand::new(KEY);
+    for lambda in [0.5, 4.0, 30.0, 1200.0].iter().copied() {
+        let poisson = Poisson::new(lambda).unwrap();
+        let samples: Vec<f64> = (0..SAMPLES / 10).map(|_| poisson.sample(&rand) as f64).collect();
+        let (mean, variance) = mean_variance(&samples);
+        assert!((mean - lambda).abs() < 0.03 * lambda, "lambda={} mean={}", lambda, mean);
+        assert!((variance - lambda).abs() < 0.05 * lambda, "lambda={} variance={}", lambda, variance);
+    }
+
+    assert_eq!(Poisson::new(0.0).unwrap_err(), distr::Error::InvalidRate);
+    assert_eq!(Poisson::new(-1.0).unwrap_err(), distr::Error::InvalidRate);
+    assert_eq!(Poisson::new(f64::INFINITY).unwrap_err(), distr::Error::InvalidRate);
+}
+
+#[test]
+fn verify_sample_iter() {
+    let rand = Rand::new(KEY);
+    let expected = Rand::new(KEY);
+
+    let bernoulli = Bernoulli::from_ratio(1, 3).unwrap();
+    let values: Vec<bool> = bernoulli.sample_iter(&rand).take(100).collect();
+    for value in values {
+        assert_eq!(value, bernoulli.sample(&expected));
+    }
+
+    let values: Vec<u32> = (5u32..10).sample_iter(&rand).take(100).collect();
+    for value in values {
+        assert_eq!(value, expected.gen_range(5u32..10));
+    }
+
+    let values: Vec<i64> = (&(-5i64..=5)).sample_iter(&rand).take(100).collect();
+    for value in values {
+        assert_eq!(value, (-5i64..=5).sample(&expected));
+    }
+    assert_eq!(rand.counter(), expected.counter());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_gamma() {
+    use distr::Gamma;
+
+    let rand = Rand::new(KEY);
+    for (shape, scale) in [(0.5, 1.0), (1.0, 2.0), (2.5, 0.5), (9.0, 3.0)].iter().copied() {
+        let gamma = Gamma::new(shape, scale).unwrap();
+        let samples: Vec<f64> = (0..SAMPLES).map(|_| gamma.sample(&rand)).collect();
+        assert!(samples.iter().all(|value| *value >= 0.0 && value.is_finite()));
+
+        let (mean, variance) = mean_variance(&samples);
+        let expected = shape * scale;
+        assert!((mean - expected).abs() < 0.02 * expected, "shape={} scale={} mean={}", shape, scale, mean);
+        let expected = shape * scale * scale;
+        assert!((variance - expected).abs() < 0.05 * expected, "shape={} scale={} variance={}", shape, scale, variance);
+    }
+
+    assert_eq!(Gamma::new(0.0, 1.0).unwrap_err(), distr::Error::InvalidShape);
+    assert_eq!(Gamma::new(f64::NAN, 1.0).unwrap_err(), distr::Error::InvalidShape);
+    assert_eq!(Gamma::new(1.0, 0.0).unwrap_err(), distr::Error::InvalidScale);
+    assert_eq!(Gamma::new(1.0, -1.0).unwrap_err(), distr::Error::InvalidScale);
+}
+
+#[test]
+fn verify_binomial() {
+    use distr::Binomial;
+
+    let rand = Rand::new(KEY);
+    for (n, p) in [(1, 0.5), (10, 0.3), (100, 0.9)].iter().copied() {
+        let binomial = Binomial::new(n, p).unwrap();
+        let samples: Vec<u64> = (0..SAMPLES / 10).map(|_| binomial.sample(&rand)).collect();
+        assert!(samples.iter().all(|value| *value <= n));
+
+        let mean = samples.iter().sum::<u64>() as f64 / samples.len() as f64;
+        let expected = n as f64 * p;
+        assert!((mean - expected).abs() < 0.02 * expected, "n={} p={} mean={}", n, p, mean);
+    }
+
+    let counter = rand.counter();
+    assert_eq!(Binomial::new(10, 0.0).unwrap().sample(&rand), 0);
+    assert_eq!(Binomial::new(10, 1.0).unwrap().sample(&rand), 10);
+    assert_eq!(rand.counter(), counter);
+
+    assert_eq!(Binomial::new(10, 1.5).unwrap_err(), distr::Error::InvalidProbability);
+    assert_eq!(Binomial::new(10, -0.5).unwrap_err(), distr::Error::InvalidProbability);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_beta() {
+    use distr::Beta;
+
+    let rand = Rand::new(KEY);
+    for (alpha, beta) in [(0.5, 0.5), (1.0, 1.0), (2.0, 5.0), (10.0, 3.0)].iter().copied() {
+        let dist = Beta::new(alpha, beta).unwrap();
+        let samples: Vec<f64> = (0..SAMPLES).map(|_| dist.sample(&rand)).collect();
+        assert!(samples.iter().all(|value| (0.0..=1.0).contains(value)));
+
+        let (mean, _) = mean_variance(&samples);
+        let expected = alpha / (alpha + beta);
+        assert!((mean - expected).abs() < 0.01, "alpha={} beta={} mean={}", alpha, beta, mean);
+    }
+
+    //Gamma draws of tiny shapes underflow to zero
+    for (alpha, beta) in [(0.001, 0.001), (0.001, 2.0), (1e-5, 0.5)].iter().copied() {
+        let dist = Beta::new(alpha, beta).unwrap();
+        for _ in 0..SAMPLES {
+            let value = dist.sample(&rand);
+            assert!((0.0..=1.0).contains(&value), "alpha={} beta={} value={}", alpha, beta, value);
+        }
+    }
+    let dist = Beta::new(0.001, 0.001).unwrap();
+    let samples: Vec<f64> = (0..SAMPLES).map(|_| dist.sample(&rand)).collect();
+    let (mean, _) = mean_variance(&samples);
+    assert!((mean - 0.5).abs() < 0.01, "mean={}", mean);
+
+    assert_eq!(Beta::new(0.0, 1.0).unwrap_err(), distr::Error::InvalidShape);
+    assert_eq!(Beta::new(1.0, -1.0).unwrap_err(), distr::Error::InvalidShape);
+    assert_eq!(Beta::new(1.0, f64::INFINITY).unwrap_err(), distr::Error::InvalidShape);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_log_uniform() {
+    use distr::LogUniform;
+
+    let rand = Rand::new(KEY);
+    for (low, high) in [(1e-5, 1e-1), (0.5, 2.0), (1.0, 1e6)].iter().copied() {
+        let dist = LogUniform::new(low, high).unwrap();
+        let samples: Vec<f64> = (0..SAMPLES).map(|_| dist.sample(&rand)).collect();
+        assert!(samples.iter().all(|value| *value >= low && *value <= high));
+
+        //ln(samples) must be uniform within [ln(low), ln(high)]
+        let (ln_low, ln_high) = (low.ln(), high.ln());
+        let mut buckets = [0usize; 10];
+        for value in samples.iter() {
+            let bucket = ((value.ln() - ln_low) / (ln_high - ln_low) * 10.0) as usize;
+            buckets[bucket.min(9)] += 1;
+        }
+        for count in buckets.iter() {
+            let ratio = *count as f64 / SAMPLES as f64;
+            assert!((ratio - 0.1).abs() < 0.01, "low={} high={} ratio={}", low, high, ratio);
+        }
+    }
+
+    assert_eq!(LogUniform::new(2.0, 2.0).unwrap().sample(&rand), 2.0);
+    assert_eq!(LogUniform::new(0.0, 1.0).unwrap_err(), distr::Error::InvalidRange);
+    assert_eq!(LogUniform::new(-1.0, 1.0).unwrap_err(), distr::Error::InvalidRange);
+    assert_eq!(LogUniform::new(2.0, 1.0).unwrap_err(), distr::Error::InvalidRange);
+    assert_eq!(LogUniform::new(1.0, f64::INFINITY).unwrap_err(), distr::Error::InvalidRange);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_cauchy() {
+    use distr::Cauchy;
+
+    let rand = Rand::new(KEY);
+    for (median, scale) in [(0.0, 1.0), (-5.0, 0.5), (100.0, 10.0)].iter().copied() {
+        let dist = Cauchy::new(median, scale).unwrap();
+        let mut samples: Vec<f64> = (0..SAMPLES).map(|_| dist.sample(&rand)).collect();
+        assert!(samples.iter().all(|value| value.is_finite()));
+
+        samples.sort_by(|left, right| left.partial_cmp(right).unwrap());
+        let actual = samples[SAMPLES / 2];
+        assert!((actual - median).abs() < 0.02 * scale, "median={} scale={} actual={}", median, scale, actual);
+
+        //Quartiles are at median -/+ scale
+        let lower = samples[SAMPLES / 4];
+        let upper = samples[3 * SAMPLES / 4];
+        assert!((lower - (median - scale)).abs() < 0.05 * scale, "median={} scale={} lower={}", median, scale, lower);
+        assert!((upper - (median + scale)).abs() < 0.05 * scale, "median={} scale={} upper={}", median, scale, upper);
+    }
+
+    assert_eq!(Cauchy::new(0.0, 0.0).unwrap_err(), distr::Error::InvalidScale);
+    assert_eq!(Cauchy::new(0.0, -1.0).unwrap_err(), distr::Error::InvalidScale);
+    assert_eq!(Cauchy::new(0.0, f64::NAN).unwrap_err(), distr::Error::InvalidScale);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_pareto() {
+    use distr::Pareto;
+
+    let rand = Rand::new(KEY);
+    for (scale, shape) in [(1.0, 1.0), (2.0, 3.0), (0.5, 0.5)].iter().copied() {
+        let dist = Pareto::new(scale, shape).unwrap();
+        let samples: Vec<f64> = (0..SAMPLES).map(|_| dist.sample(&rand)).collect();
+        assert!(samples.iter().all(|value| *value >= scale && value.is_finite()));
+
+        //P(X > scale * t) = t^-shape
+        for t in [2.0f64, 4.0].iter().copied() {
+            let tail = samples.iter().filter(|value| **value > scale * t).count() as f64 / SAMPLES as f64;
+            let expected = t.powf(-shape);
+            assert!((tail - expected).abs() < 0.01, "scale={} shape={} t={} tail={}", scale, shape, t, tail);
+        }
+    }
+
+    //Power of small shape underflows
+    let dist = Pareto::new(1.0, 0.001).unwrap();
+    for _ in 0..SAMPLES {
+        let value = dist.sample(&rand);
+        assert!(value >= 1.0 && value.is_finite(), "value={}", value);
+    }
+
+    assert_eq!(Pareto::new(0.0, 1.0).unwrap_err(), distr::Error::InvalidScale);
+    assert_eq!(Pareto::new(f64::INFINITY, 1.0).unwrap_err(), distr::Error::InvalidScale);
+    assert_eq!(Pareto::new(1.0, 0.0).unwrap_err(), distr::Error::InvalidShape);
+    assert_eq!(Pareto::new(1.0, -2.0).unwrap_err(), distr::Error::InvalidShape);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_chi_squared() {
+    use distr::ChiSquared;
+
+    let rand = Rand::new(KEY);
+    for freedom in [0.5, 1.0, 2.5, 3.0, 10.0].iter().copied() {
+        let dist = ChiSquared::new(freedom).unwrap();
+        let samples: Vec<f64> = (0..SAMPLES).map(|_| dist.sample(&rand)).collect();
+        assert!(samples.iter().all(|value| *value >= 0.0 && value.is_finite()));
+
+        let (mean, variance) = mean_variance(&samples);
+        assert!((mean - freedom).abs() < 0.02 * freedom, "freedom={} mean={}", freedom, mean);
+        let expected = 2.0 * freedom;
+        assert!((variance - expected).abs() < 0.05 * expected, "freedom={} variance={}", freedom, variance);
+    }
+
+    assert_eq!(ChiSquared::new(0.0).unwrap_err(), distr::Error::InvalidShape);
+    assert_eq!(ChiSquared::new(-3.0).unwrap_err(), distr::Error::InvalidShape);
+    assert_eq!(ChiSquared::new(f64::INFINITY).unwrap_err(), distr::Error::InvalidShape);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_students_t() {
+    use distr::StudentsT;
+
+    let rand = Rand::new(KEY);
+    for freedom in [10.0, 50.0, 1000.0].iter().copied() {
+        let dist = StudentsT::new(freedom).unwrap();
+        let samples: Vec<f64> = (0..SAMPLES).map(|_| dist.sample(&rand)).collect();
+        assert!(samples.iter().all(|value| value.is_finite()));
+
+        let (mean, variance) = mean_variance(&samples);
+        let expected = freedom / (freedom - 2.0);
+        assert!(mean.abs() < 0.02, "freedom={} mean={}", freedom, mean);
+        assert!((variance - expected).abs() < 0.05 * expected, "freedom={} variance={}", freedom, variance);
+    }
+
+    //Approaches standard normal
+    let dist = StudentsT::new(1000.0).unwrap();
+    let samples: Vec<f64> = (0..SAMPLES).map(|_| dist.sample(&rand)).collect();
+    let (_, variance) = mean_variance(&samples);
+    assert!((variance - 1.0).abs() < 0.02, "variance={}", variance);
+
+    assert_eq!(StudentsT::new(0.0).unwrap_err(), distr::Error::InvalidShape);
+    assert_eq!(StudentsT::new(-1.0).unwrap_err(), distr::Error::InvalidShape);
+    assert_eq!(StudentsT::new(f64::NAN).unwrap_err(), distr::Error::InvalidShape);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_log_normal() {
+    use distr::LogNormal;
+
+    let rand = Rand::new(KEY);
+    for (mu, sigma) in [(0.0, 1.0), (2.0, 0.5), (-1.0, 0.25)].iter().copied() {
+        let dist = LogNormal::new(mu, sigma).unwrap();
+        let samples: Vec<f64> = (0..SAMPLES).map(|_| dist.sample(&rand)).collect();
+        assert!(samples.iter().all(|value| *value > 0.0 && value.is_finite()));
+
+        let logs: Vec<f64> = samples.iter().map(|value| value.ln()).collect();
+        let (mean, variance) = mean_variance(&logs);
+        assert!((mean - mu).abs() < 0.01, "mu={} sigma={} mean={}", mu, sigma, mean);
+        assert!((variance.sqrt() - sigma).abs() < 0.01 * sigma.max(1.0), "mu={} sigma={} std_dev={}", mu, sigma, variance.sqrt());
+    }
+
+    assert_eq!(LogNormal::new(0.0, 0.0).unwrap_err(), distr::Error::InvalidStdDev);
+    assert_eq!(LogNormal::new(0.0, -1.0).unwrap_err(), distr::Error::InvalidStdDev);
+    assert_eq!(LogNormal::new(0.0, f64::INFINITY).unwrap_err(), distr::Error::InvalidStdDev);
+}
+
+#[test]
+fn verify_ordering() {
+    let rand = Rand::new(KEY).with_ordering::<squares_rnd::ordering::Relaxed>();
+    let expected = Rand::new(KEY);
+
+    let bernoulli = Bernoulli::new(0.3).unwrap();
+    for _ in 0..100 {
+        assert_eq!(bernoulli.sample(&rand), bernoulli.sample(&expected));
+    }
+    let values: Vec<u32> = (5..10).sample_iter(&rand).take(10).collect();
+    let expected: Vec<u32> = (5..10).sample_iter(&expected).take(10).collect();
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn verify_fill() {
+    let rand = Rand::new(KEY);
+    let expected = Rand::new(KEY);
+
+    let bernoulli = Bernoulli::new(0.3).unwrap();
+    let mut buffer = [false; 100];
+    bernoulli.fill(&rand, &mut buffer);
+    for value in buffer.iter() {
+        assert_eq!(*value, bernoulli.sample(&expected));
+    }
+
+    let mut buffer = [0u32; 10];
+    (5..10).fill(&rand, &mut buffer);
+    for value in buffer.iter() {
+        assert_eq!(*value, (5..10).sample(&expected));
+    }
+    assert_eq!(rand.counter(), expected.counter());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_fill_normal() {
+    use distr::Normal;
+
+    let rand = Rand::new(KEY);
+    let expected = Rand::new(KEY);
+    let normal = Normal::new(1.0, 2.0).unwrap();
+    let expected_normal = normal;
+
+    for len in [0, 1, 7, 64].iter().copied() {
+        let mut buffer = vec![0.0; len];
+        normal.fill(&rand, &mut buffer);
+        for value in buffer {
+            assert_eq!(value, expected_normal.sample(&expected));
+        }
+    }
+    assert_eq!(rand.counter(), expected.counter());
+}
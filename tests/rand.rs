@@ -1,229 +1,2036 @@
-use squares_rnd::{KEY, Rand};
+use squares_rnd::{KEY, Rand, RandCell, next32, next64, rand32, rand64};
+
+//Outputs of default key, starting from counter 0
+const EXPECTED_U32: [u32; 100] = [
+    3352654702u32,
+    4074910648,
+    2177904447,
+    3591997784,
+    309172526,
+    14118540,
+    1376456388,
+    1817611776,
+    2658708129,
+    3816631332,
+    2074957304,
+    1197607180,
+    3382851809,
+    4630807,
+    969366713,
+    1214429827,
+    1551357583,
+    964614781,
+    885797288,
+    228455009,
+    3795099028,
+    2846967822,
+    860132314,
+    2417913545,
+    549388739,
+    1054086960,
+    3198953764,
+    1017529211,
+    4071162903,
+    1860614063,
+    976680366,
+    2474148318,
+    2555832691,
+    5746874,
+    2213997678,
+    4127664552,
+    1824987508,
+    2003555489,
+    669285426,
+    1956273251,
+    1331312278,
+    384168544,
+    767219735,
+    1703125500,
+    1151964577,
+    1451362376,
+    786830311,
+    3007546033,
+    1978998827,
+    3677699848,
+    1875248042,
+    3251648427,
+    2883285475,
+    1388411661,
+    3702855681,
+    1686575763,
+    3869385310,
+    1786705876,
+    1811588117,
+    1271892253,
+    3435479074,
+    2806725047,
+    1987296021,
+    4264790073,
+    3869965352,
+    1668365357,
+    1992522618,
+    2590424441,
+    2063541441,
+    1566680018,
+    3094852598,
+    3288611027,
+    2142634010,
+    2479402868,
+    775827867,
+    3363128138,
+    2433828005,
+    3871725313,
+    453889514,
+    2016441173,
+    404358135,
+    4200715467,
+    3466728357,
+    2124499209,
+    3091969433,
+    4224907557,
+    3597787665,
+    944831321,
+    247498744,
+    1409630787,
+    956022842,
+    913395360,
+    951808902,
+    1301972083,
+    335847163,
+    2543114637,
+    1852668585,
+    4090590393,
+    2745796757,
+    727871298,
+];
+const EXPECTED_U64: [u64; 100] = [
+    14399542303155484263u64,
+    17501607969359464034,
+    9354028374942533382,
+    15427513012393281532,
+    1327885891373351147,
+    60638670215810043,
+    5911835172535428600,
+    7806583138883068395,
+    11419064466034786357,
+    16392306754029120696,
+    8911873762093407361,
+    5143683672172701690,
+    14529237890604122752,
+    19889166102031785,
+    4163398334406911152,
+    5215936393198264659,
+    6663030086176828147,
+    4142988939815573669,
+    3804470384634822310,
+    981206793566959392,
+    16299826212061670494,
+    12227633688552008664,
+    3694240162244720062,
+    10384859601573002243,
+    2359606668987345604,
+    4527269021397652637,
+    13739401801815258063,
+    4370254686731437278,
+    17485511525953148642,
+    7991276553396556667,
+    4194810233081806530,
+    10626386112989138509,
+    10977217822832889495,
+    24682639439007289,
+    9509047622910815416,
+    17728184262025808435,
+    7838261665743942276,
+    8605205304630665208,
+    2874559017119030751,
+    8402129636376629661,
+    5717942698132331305,
+    1649991336016897064,
+    3295183672220509950,
+    7314868326649166118,
+    4947650188088540507,
+    6233553940330143969,
+    3379410454493317030,
+    12917311855428041199,
+    8499735244970525920,
+    15795600574330558527,
+    8054129013612082032,
+    13965723653650379501,
+    12383616822729305560,
+    5963182679409556272,
+    15903644052678103379,
+    7243787744618625032,
+    16618883363856591479,
+    7673843308844980174,
+    7780711718399524232,
+    5462735632226813910,
+    14755270269289560618,
+    12054792288415653926,
+    8535371421003719263,
+    18317133887998968270,
+    16621374626717847180,
+    7165574647168350039,
+    8557819483486794023,
+    11125788260570175986,
+    8862843003249831367,
+    6728839444092422614,
+    13292290694420989225,
+    14124476813394813502,
+    9202543002727219547,
+    10648954232663981208,
+    3332155316792614203,
+    14444525369156352631,
+    10453211686892771026,
+    16628933598458950702,
+    1949440621185508190,
+    8660548895303310226,
+    1736704969262069192,
+    18041935551059624115,
+    14889484920543723135,
+    9124654623922895816,
+    13279907595754884703,
+    18145839787438662779,
+    15452380362066314963,
+    4058019627408702579,
+    1062999014878645618,
+    6054318132808991276,
+    4106086844527938010,
+    3923003203613301236,
+    4087988107706547008,
+    5591927520822166873,
+    1442452585074274494,
+    10922594199957697924,
+    7957150986473910430,
+    17568951962841284160,
+    11793107274449697652,
+    3126183424824572504,
+];
 
 #[test]
 fn verify_u32() {
-    let expected = [
-        3352654702u32,
-        4074910648,
-        2177904447,
-        3591997784,
-        309172526,
-        14118540,
-        1376456388,
-        1817611776,
-        2658708129,
-        3816631332,
-        2074957304,
-        1197607180,
-        3382851809,
-        4630807,
-        969366713,
-        1214429827,
-        1551357583,
-        964614781,
-        885797288,
-        228455009,
-        3795099028,
-        2846967822,
-        860132314,
-        2417913545,
-        549388739,
-        1054086960,
-        3198953764,
-        1017529211,
-        4071162903,
-        1860614063,
-        976680366,
-        2474148318,
-        2555832691,
-        5746874,
-        2213997678,
-        4127664552,
-        1824987508,
-        2003555489,
-        669285426,
-        1956273251,
-        1331312278,
-        384168544,
-        767219735,
-        1703125500,
-        1151964577,
-        1451362376,
-        786830311,
-        3007546033,
-        1978998827,
-        3677699848,
-        1875248042,
-        3251648427,
-        2883285475,
-        1388411661,
-        3702855681,
-        1686575763,
-        3869385310,
-        1786705876,
-        1811588117,
-        1271892253,
-        3435479074,
-        2806725047,
-        1987296021,
-        4264790073,
-        3869965352,
-        1668365357,
-        1992522618,
-        2590424441,
-        2063541441,
-        1566680018,
-        3094852598,
-        3288611027,
-        2142634010,
-        2479402868,
-        775827867,
-        3363128138,
-        2433828005,
-        3871725313,
-        453889514,
-        2016441173,
-        404358135,
-        4200715467,
-        3466728357,
-        2124499209,
-        3091969433,
-        4224907557,
-        3597787665,
-        944831321,
-        247498744,
-        1409630787,
-        956022842,
-        913395360,
-        951808902,
-        1301972083,
-        335847163,
-        2543114637,
-        1852668585,
-        4090590393,
-        2745796757,
-        727871298,
-    ];
-
-    let rand = Rand::new(KEY);
-    for expected in expected.chunks(2) {
+    let rand = Rand::new(KEY);
+    for expected in EXPECTED_U32.chunks(2) {
         assert_eq!(rand.next_u32(), expected[0]);
         let res = rand.next_full_u32();
-        assert!(res.counter % 2 != 0); //counter starts from 0 so always must be true
+        assert!(res.counter % 2 == 1); //counter starts from 0 so always must be true
         assert_eq!(res.value, expected[1]);
     }
 }
 
 #[test]
 fn verify_u64() {
-    let expected = [
-        14399542303155484263u64,
-        17501607969359464034,
-        9354028374942533382,
-        15427513012393281532,
-        1327885891373351147,
-        60638670215810043,
-        5911835172535428600,
-        7806583138883068395,
-        11419064466034786357,
-        16392306754029120696,
-        8911873762093407361,
-        5143683672172701690,
-        14529237890604122752,
-        19889166102031785,
-        4163398334406911152,
-        5215936393198264659,
-        6663030086176828147,
-        4142988939815573669,
-        3804470384634822310,
-        981206793566959392,
-        16299826212061670494,
-        12227633688552008664,
-        3694240162244720062,
-        10384859601573002243,
-        2359606668987345604,
-        4527269021397652637,
-        13739401801815258063,
-        4370254686731437278,
-        17485511525953148642,
-        7991276553396556667,
-        4194810233081806530,
-        10626386112989138509,
-        10977217822832889495,
-        24682639439007289,
-        9509047622910815416,
-        17728184262025808435,
-        7838261665743942276,
-        8605205304630665208,
-        2874559017119030751,
-        8402129636376629661,
-        5717942698132331305,
-        1649991336016897064,
-        3295183672220509950,
-        7314868326649166118,
-        4947650188088540507,
-        6233553940330143969,
-        3379410454493317030,
-        12917311855428041199,
-        8499735244970525920,
-        15795600574330558527,
-        8054129013612082032,
-        13965723653650379501,
-        12383616822729305560,
-        5963182679409556272,
-        15903644052678103379,
-        7243787744618625032,
-        16618883363856591479,
-        7673843308844980174,
-        7780711718399524232,
-        5462735632226813910,
-        14755270269289560618,
-        12054792288415653926,
-        8535371421003719263,
-        18317133887998968270,
-        16621374626717847180,
-        7165574647168350039,
-        8557819483486794023,
-        11125788260570175986,
-        8862843003249831367,
-        6728839444092422614,
-        13292290694420989225,
-        14124476813394813502,
-        9202543002727219547,
-        10648954232663981208,
-        3332155316792614203,
-        14444525369156352631,
-        10453211686892771026,
-        16628933598458950702,
-        1949440621185508190,
-        8660548895303310226,
-        1736704969262069192,
-        18041935551059624115,
-        14889484920543723135,
-        9124654623922895816,
-        13279907595754884703,
-        18145839787438662779,
-        15452380362066314963,
-        4058019627408702579,
-        1062999014878645618,
-        6054318132808991276,
-        4106086844527938010,
-        3923003203613301236,
-        4087988107706547008,
-        5591927520822166873,
-        1442452585074274494,
-        10922594199957697924,
-        7957150986473910430,
-        17568951962841284160,
-        11793107274449697652,
-        3126183424824572504,
-    ];
-
-    let rand = Rand::new(KEY);
-    for expected in expected.chunks(2) {
+    let rand = Rand::new(KEY);
+    for expected in EXPECTED_U64.chunks(2) {
         assert_eq!(rand.next_u64(), expected[0]);
         let res = rand.next_full_u64();
-        assert!(res.counter % 2 != 0); //counter starts from 0 so always must be true
+        assert!(res.counter % 2 == 1); //counter starts from 0 so always must be true
         assert_eq!(res.value, expected[1]);
     }
 }
+
+#[test]
+fn verify_u64_extends_u32() {
+    const HIGH: u32 = (rand64(0, KEY) >> 32) as u32;
+    const EXPECTED: u32 = rand32(0, KEY);
+    assert_eq!(HIGH, EXPECTED);
+
+    for key in [KEY, 0x5555_5555_5555_5555, 1].iter().copied() {
+        for counter in [0, 1, 2, 1000, 0xdead_beef, u64::MAX / 2, u64::MAX - 1, u64::MAX].iter().copied() {
+            assert_eq!((rand64(counter, key) >> 32) as u32, rand32(counter, key), "counter={} key={:x}", counter, key);
+        }
+    }
+}
+
+#[test]
+fn verify_fill_bytes() {
+    let rand = Rand::new(KEY);
+    let mut buffer = [0u8; 5];
+    rand.fill_bytes(&mut buffer);
+    assert_eq!(rand.counter(), 1);
+    assert_eq!(buffer, rand64(0, KEY).to_le_bytes()[..5]);
+
+    let rand = Rand::new(KEY);
+    let mut buffer = [0u8; 13];
+    rand.fill_bytes(&mut buffer);
+    assert_eq!(rand.counter(), 2);
+    assert_eq!(buffer[..8], rand64(0, KEY).to_le_bytes());
+    assert_eq!(buffer[8..], rand64(1, KEY).to_le_bytes()[..5]);
+
+    let rand = Rand::new(KEY);
+    let mut buffer = [0u8; 16];
+    rand.fill_bytes(&mut buffer);
+    assert_eq!(rand.counter(), 2);
+    assert_eq!(rand.next_u64(), rand64(2, KEY));
+
+    rand.fill_bytes(&mut []);
+    assert_eq!(rand.counter(), 3);
+}
+
+#[test]
+fn verify_fill_bytes_at() {
+    let rand = Rand::with_counter(10, KEY);
+    for start_counter in [0, 1000, u64::MAX].iter().copied() {
+        let mut buffer = [0u8; 21];
+        rand.fill_bytes_at(start_counter, &mut buffer);
+        assert_eq!(rand.counter(), 10);
+
+        let mut same = [0u8; 21];
+        rand.fill_bytes_at(start_counter, &mut same);
+        assert_eq!(buffer, same);
+
+        let mut expected = [0u8; 21];
+        Rand::with_counter(start_counter, KEY).fill_bytes(&mut expected);
+        assert_eq!(buffer, expected);
+    }
+}
+
+#[test]
+fn verify_float_unit_range() {
+    let rand = Rand::new(KEY);
+    for _ in 0..300_000 {
+        let value = rand.next_f64();
+        assert!((0.0..1.0).contains(&value), "value={}", value);
+
+        let value = rand.next_f32();
+        assert!((0.0..1.0).contains(&value), "value={}", value);
+    }
+}
+
+#[test]
+fn verify_f64_range() {
+    let rand = Rand::new(KEY);
+    for _ in 0..100_000 {
+        let value = rand.next_f64_range(-5.0, 10.0);
+        assert!((-5.0..10.0).contains(&value), "value={}", value);
+
+        let value = rand.next_f64_range(f64::MIN, f64::MAX);
+        assert!(value.is_finite(), "value={}", value);
+    }
+
+    assert_eq!(rand.next_f64_range(1.5, 1.5), 1.5);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_jitter() {
+    use core::time::Duration;
+
+    let rand = Rand::new(KEY);
+    for base in [Duration::ZERO, Duration::from_millis(1), Duration::from_secs(30)].iter().copied() {
+        for factor in [0.0, 0.1, 0.5, 1.0].iter().copied() {
+            let low = base.mul_f64(1.0 - factor);
+            let high = base.mul_f64(1.0 + factor);
+            for _ in 0..1000 {
+                let value = rand.jitter(base, factor);
+                assert!(value >= low && value <= high, "base={:?} factor={} value={:?}", base, factor, value);
+            }
+        }
+    }
+
+    assert_eq!(rand.jitter(Duration::from_secs(1), 0.0), Duration::from_secs(1));
+    let base = Duration::from_secs(1);
+    for _ in 0..1000 {
+        assert!(rand.jitter(base, 3.0) <= base * 4);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic]
+fn verify_jitter_negative_factor() {
+    Rand::new(KEY).jitter(core::time::Duration::from_secs(1), -1.0);
+}
+
+#[test]
+#[should_panic]
+fn verify_f64_range_reversed() {
+    let rand = Rand::new(KEY);
+    rand.next_f64_range(10.0, -5.0);
+}
+
+#[cfg(feature = "rand_core")]
+#[test]
+fn verify_rand_core() {
+    use rand_core::{RngCore, SeedableRng};
+
+    let mut rand = Rand::from_seed(5u64.to_le_bytes());
+    assert_eq!(rand.counter(), 5);
+    assert_eq!(RngCore::next_u32(&mut rand), rand32(5, KEY));
+    assert_eq!(RngCore::next_u64(&mut rand), rand64(6, KEY));
+
+    let mut buffer = [0u8; 13];
+    RngCore::fill_bytes(&mut rand, &mut buffer);
+    assert_eq!(buffer[..8], rand64(7, KEY).to_le_bytes());
+    assert_eq!(rand.counter(), 9);
+}
+
+#[test]
+fn verify_next_explicit_state() {
+    const FIRST: (u32, u64) = next32(0, KEY);
+    assert_eq!(FIRST, (rand32(0, KEY), 1));
+    assert_eq!(next64(u64::MAX, KEY), (rand64(u64::MAX, KEY), 0));
+
+    let rand = Rand::new(KEY);
+    let mut counter = 0;
+    for _ in 0..100 {
+        let (value, next) = next32(counter, KEY);
+        assert_eq!(value, rand.next_u32());
+        let (value, next) = next64(next, KEY);
+        assert_eq!(value, rand.next_u64());
+        counter = next;
+    }
+    assert_eq!(counter, rand.counter());
+}
+
+#[test]
+fn verify_rand_cell() {
+    let rand = Rand::new(KEY);
+    let cell = RandCell::new(KEY);
+
+    for _ in 0..100 {
+        assert_eq!(rand.next_u32(), cell.next_u32());
+        assert_eq!(rand.next_u64(), cell.next_u64());
+        assert_eq!(rand.next_u32_up(7), cell.next_u32_up(7));
+        assert_eq!(rand.next_u64_up(1000), cell.next_u64_up(1000));
+    }
+    assert_eq!(rand.counter(), cell.counter());
+
+    let res = cell.next_full_u64();
+    assert_eq!(res.counter, rand.counter());
+    assert_eq!(res.value, rand.next_u64());
+}
+
+#[test]
+fn verify_jump() {
+    let rand = Rand::new(KEY);
+    assert_eq!(rand.jump(10), 0);
+    assert_eq!(rand.counter(), 10);
+    assert_eq!(rand.next_u64(), rand64(10, KEY));
+
+    let worker = Rand::with_counter(rand.jump(5), KEY);
+    assert_eq!(worker.next_u64(), rand64(11, KEY));
+    assert_eq!(rand.next_u64(), rand64(16, KEY));
+
+    rand.set_counter(u64::MAX);
+    assert_eq!(rand.jump(2), u64::MAX);
+    assert_eq!(rand.counter(), 1);
+}
+
+#[test]
+fn verify_advance_to() {
+    let rand = Rand::with_counter(100, KEY);
+    assert!(rand.advance_to(150));
+    assert_eq!(rand.counter(), 150);
+    assert_eq!(rand.next_u64(), rand64(150, KEY));
+
+    assert!(!rand.advance_to(151));
+    assert_eq!(rand.counter(), 151);
+    assert!(!rand.advance_to(10));
+    assert_eq!(rand.counter(), 151);
+
+    assert!(rand.advance_to(u64::MAX));
+    assert_eq!(rand.counter(), u64::MAX);
+}
+
+#[test]
+fn verify_from_seed_bytes() {
+    use std::collections::HashSet;
+
+    let rand = Rand::from_seed_bytes(b"content hash");
+    assert_eq!(rand.key(), KEY);
+    assert_eq!(Rand::from_seed_bytes(b"content hash").counter(), rand.counter());
+
+    let first = rand64(12 ^ u64::from_le_bytes(*b"content "), KEY);
+    let mut tail = [0u8; 8];
+    tail[..4].copy_from_slice(b"hash");
+    assert_eq!(rand.counter(), rand64(first ^ u64::from_le_bytes(tail), KEY));
+
+    assert_eq!(Rand::from_seed_bytes(&[]).counter(), 0);
+    let seeds: [&[u8]; 6] = [&[], &[0], &[0, 0], &[1], b"content hash", b"content hasH"];
+    let counters: HashSet<u64> = seeds.iter().map(|seed| Rand::from_seed_bytes(seed).counter()).collect();
+    assert_eq!(counters.len(), seeds.len());
+}
+
+#[test]
+fn verify_split() {
+    use std::collections::HashSet;
+
+    let rand = Rand::with_counter(100, KEY);
+    let mut children = rand.split(2);
+    let first = children.next().unwrap();
+    let second = children.next().unwrap();
+    assert!(children.next().is_none());
+    assert_eq!(rand.counter(), 100);
+    assert_eq!(first.counter(), 100 + u64::MAX / 3);
+    assert_eq!(second.counter(), 100u64.wrapping_add(2 * (u64::MAX / 3)));
+
+    let first: HashSet<u64> = (0..10_000).map(|_| first.next_u64()).collect();
+    let second: HashSet<u64> = (0..10_000).map(|_| second.next_u64()).collect();
+    let parent: HashSet<u64> = (0..10_000).map(|_| rand.next_u64()).collect();
+    assert!(first.is_disjoint(&second));
+    assert!(first.is_disjoint(&parent));
+    assert!(second.is_disjoint(&parent));
+
+    assert_eq!(rand.split(0).count(), 0);
+}
+
+#[test]
+fn verify_with_stream() {
+    use std::collections::HashSet;
+
+    assert_eq!(Rand::with_stream(KEY, 0).counter(), 0);
+    assert_eq!(Rand::with_stream(KEY, 3).counter(), 3 << 32);
+    assert_eq!(Rand::with_stream(KEY, 1).key(), KEY);
+
+    let first = Rand::with_stream(KEY, 1);
+    let second = Rand::with_stream(KEY, 2);
+    let first: Vec<f64> = (0..10_000).map(|_| first.next_f64()).collect();
+    let second: Vec<f64> = (0..10_000).map(|_| second.next_f64()).collect();
+
+    let first_set: HashSet<u64> = first.iter().map(|value| value.to_bits()).collect();
+    assert!(second.iter().all(|value| !first_set.contains(&value.to_bits())));
+
+    let len = first.len() as f64;
+    let first_mean = first.iter().sum::<f64>() / len;
+    let second_mean = second.iter().sum::<f64>() / len;
+    let mut covariance = 0.0;
+    let mut first_variance = 0.0;
+    let mut second_variance = 0.0;
+    for (x, y) in first.iter().zip(second.iter()) {
+        covariance += (x - first_mean) * (y - second_mean);
+        first_variance += (x - first_mean) * (x - first_mean);
+        second_variance += (y - second_mean) * (y - second_mean);
+    }
+    let correlation = covariance / (first_variance * second_variance).sqrt();
+    assert!(correlation.abs() < 0.05, "correlation={}", correlation);
+}
+
+#[test]
+fn verify_with_domain() {
+    use squares_rnd::is_good_key;
+    use std::collections::HashSet;
+
+    let physics = Rand::with_domain(KEY, u64::from_le_bytes(*b"physics\0"));
+    let audio = Rand::with_domain(KEY, u64::from_le_bytes(*b"audio\0\0\0"));
+    assert_eq!(physics.counter(), 0);
+    assert_eq!(audio.counter(), 0);
+    assert!(is_good_key(physics.key()));
+    assert_ne!(physics.key(), audio.key());
+    assert_ne!(Rand::with_domain(1, 7).key(), Rand::with_domain(KEY, 7).key());
+    assert_eq!(Rand::with_domain(KEY, 1).key(), Rand::with_domain(KEY, 1).key());
+
+    //Same counters must produce different output
+    let plain = Rand::new(KEY);
+    let physics_values: HashSet<u64> = (0..10_000).map(|_| physics.next_u64()).collect();
+    let audio_values: HashSet<u64> = (0..10_000).map(|_| audio.next_u64()).collect();
+    let plain_values: HashSet<u64> = (0..10_000).map(|_| plain.next_u64()).collect();
+    assert!(physics_values.is_disjoint(&audio_values));
+    assert!(physics_values.is_disjoint(&plain_values));
+    assert!(audio_values.is_disjoint(&plain_values));
+
+    let first = Rand::with_domain(KEY, 1);
+    let second = Rand::with_domain(KEY, 2);
+    first.set_counter(42);
+    second.set_counter(42);
+    assert_ne!(first.next_u64(), second.next_u64());
+    assert_ne!(first.nth_u32(7), second.nth_u32(7));
+
+    let mut first_bytes = [0u8; 16];
+    let mut second_bytes = [0u8; 16];
+    first.fill_bytes_at(100, &mut first_bytes);
+    second.fill_bytes_at(100, &mut second_bytes);
+    assert_ne!(first_bytes, second_bytes);
+}
+
+#[test]
+fn verify_bool() {
+    const SAMPLES: u32 = 100_000;
+    let rand = Rand::new(KEY);
+
+    let count = (0..SAMPLES).filter(|_| rand.next_bool()).count() as f64;
+    assert!((count / SAMPLES as f64 - 0.5).abs() < 0.01, "count={}", count);
+
+    let count = (0..SAMPLES).filter(|_| rand.next_bool_ratio(1, 4)).count() as f64;
+    assert!((count / SAMPLES as f64 - 0.25).abs() < 0.01, "count={}", count);
+
+    assert!((0..1000).all(|_| rand.next_bool_ratio(3, 3)));
+    assert!((0..1000).all(|_| !rand.next_bool_ratio(0, 3)));
+}
+
+#[test]
+#[should_panic]
+fn verify_bool_ratio_zero_denominator() {
+    let rand = Rand::new(KEY);
+    rand.next_bool_ratio(0, 0);
+}
+
+#[test]
+#[should_panic]
+fn verify_bool_ratio_invalid_numerator() {
+    let rand = Rand::new(KEY);
+    rand.next_bool_ratio(2, 1);
+}
+
+#[test]
+fn verify_permutation() {
+    let rand = Rand::with_counter(50, KEY);
+    for _ in 0..100 {
+        let mut permutation = rand.permutation::<16>();
+        permutation.sort_unstable();
+        assert!(permutation.iter().copied().eq(0..16));
+    }
+    assert_eq!(rand.counter(), 50 + 100 * 15);
+
+    let first = Rand::with_counter(50, KEY).permutation::<8>();
+    assert_eq!(Rand::with_counter(50, KEY).permutation::<8>(), first);
+
+    let mut expected = [0, 1, 2, 3, 4, 5, 6, 7];
+    Rand::with_counter(50, KEY).shuffle(&mut expected);
+    assert_eq!(first, expected);
+
+    assert_eq!(rand.permutation::<0>(), [0usize; 0]);
+    assert_eq!(rand.permutation::<1>(), [0]);
+}
+
+#[test]
+fn verify_shuffle_at() {
+    let sorted: Vec<u32> = (0..50).collect();
+    let rand = Rand::with_counter(7, KEY);
+
+    let mut shuffled = sorted.clone();
+    let end = rand.shuffle_at(1000, &mut shuffled);
+    assert_eq!(end, 1000 + 49);
+    assert_eq!(rand.counter(), 7);
+    assert_ne!(shuffled, sorted);
+
+    let mut again = sorted.clone();
+    assert_eq!(rand.shuffle_at(1000, &mut again), end);
+    assert_eq!(again, shuffled);
+
+    let expected = Rand::with_counter(1000, KEY);
+    let mut expected_shuffle = sorted.clone();
+    expected.shuffle(&mut expected_shuffle);
+    assert_eq!(expected_shuffle, shuffled);
+    assert_eq!(expected.counter(), end);
+
+    let mut other = sorted.clone();
+    rand.shuffle_at(end, &mut other);
+    assert_ne!(other, shuffled);
+}
+
+#[test]
+fn verify_shuffle() {
+    let sorted: Vec<u32> = (0..100).collect();
+
+    let rand = Rand::new(KEY);
+    let mut shuffled = sorted.clone();
+    rand.shuffle(&mut shuffled);
+    assert_ne!(shuffled, sorted);
+    assert_eq!(rand.counter(), 99);
+
+    let mut permutation = shuffled.clone();
+    permutation.sort_unstable();
+    assert_eq!(permutation, sorted);
+
+    let rand = Rand::new(KEY);
+    let mut shuffled_again = sorted.clone();
+    rand.shuffle(&mut shuffled_again);
+    assert_eq!(shuffled_again, shuffled);
+
+    let mut single = [1];
+    rand.shuffle(&mut single);
+    let mut empty: [u32; 0] = [];
+    rand.shuffle(&mut empty);
+    assert_eq!(rand.counter(), 99);
+}
+
+#[test]
+fn verify_partial_shuffle() {
+    let sorted: Vec<u32> = (0..100).collect();
+
+    let rand = Rand::new(KEY);
+    let mut shuffled = sorted.clone();
+    rand.partial_shuffle(&mut shuffled, 10);
+    assert_eq!(rand.counter(), 10);
+    assert_ne!(shuffled[..10], sorted[..10]);
+
+    let mut permutation = shuffled.clone();
+    permutation.sort_unstable();
+    assert_eq!(permutation, sorted);
+
+    let rand = Rand::new(KEY);
+    let mut full = sorted.clone();
+    rand.partial_shuffle(&mut full, 1000);
+    assert_eq!(rand.counter(), 99);
+    //Same draws are made, so prefix must match
+    assert_eq!(full[..10], shuffled[..10]);
+}
+
+#[test]
+fn verify_choose() {
+    let rand = Rand::new(KEY);
+    let empty: [u32; 0] = [];
+    assert!(rand.choose(&empty).is_none());
+    assert_eq!(rand.counter(), 0);
+
+    assert_eq!(rand.choose(&[5]), Some(&5));
+
+    let items = [1, 2, 3, 4];
+    let mut seen = [false; 4];
+    for _ in 0..1000 {
+        let item = rand.choose(&items).unwrap();
+        seen[*item as usize - 1] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+}
+
+#[test]
+fn verify_choose_mut() {
+    let rand = Rand::new(KEY);
+    let mut empty: [u32; 0] = [];
+    assert!(rand.choose_mut(&mut empty).is_none());
+
+    let mut single = [5];
+    *rand.choose_mut(&mut single).unwrap() = 10;
+    assert_eq!(single, [10]);
+}
+
+#[test]
+fn verify_signed() {
+    let rand = Rand::new(KEY);
+    assert_eq!(rand.next_i32(), 3352654702u32 as i32);
+    assert_eq!(rand.next_i64(), 17501607969359464034u64 as i64);
+
+    let mut negative = false;
+    for _ in 0..10_000 {
+        let value = rand.next_i32_range(-10, 10);
+        assert!((-10..10).contains(&value), "value={}", value);
+        negative |= value < 0;
+
+        let value = rand.next_i32_range(-100, -50);
+        assert!((-100..-50).contains(&value), "value={}", value);
+
+        let value = rand.next_i64_range(-100, -50);
+        assert!((-100..-50).contains(&value), "value={}", value);
+
+        let value = rand.next_i64_range(i64::MIN, i64::MAX);
+        assert!(value < i64::MAX);
+
+        rand.next_i32_range(i32::MIN, i32::MAX);
+    }
+    assert!(negative);
+
+    assert_eq!(rand.next_i32_range(-5, -4), -5);
+    assert_eq!(rand.next_i64_range(i64::MAX - 1, i64::MAX), i64::MAX - 1);
+}
+
+#[test]
+#[should_panic]
+fn verify_signed_empty_range() {
+    let rand = Rand::new(KEY);
+    rand.next_i32_range(5, 5);
+}
+
+#[test]
+fn verify_unsigned_inclusive_range() {
+    let rand = Rand::new(KEY);
+    assert_eq!(rand.next_u32_range(7, 7), 7);
+    assert_eq!(rand.next_u64_range(7, 7), 7);
+
+    let mut seen = [false; 6];
+    for _ in 0..10_000 {
+        let value = rand.next_u32_range(5, 10);
+        assert!((5..=10).contains(&value), "value={}", value);
+        seen[value as usize - 5] = true;
+
+        let value = rand.next_u64_range(u64::MAX - 3, u64::MAX);
+        assert!(value >= u64::MAX - 3);
+    }
+    assert!(seen.iter().all(|seen| *seen));
+
+    let counter = rand.counter();
+    assert_eq!(rand.next_u32_range(0, u32::MAX), rand32(counter, KEY));
+    assert_eq!(rand.next_u64_range(0, u64::MAX), rand64(counter + 1, KEY));
+}
+
+#[test]
+#[should_panic]
+fn verify_unsigned_inclusive_range_reversed() {
+    let rand = Rand::new(KEY);
+    rand.next_u64_range(2, 1);
+}
+
+#[test]
+fn verify_iter() {
+    let rand = Rand::new(KEY);
+    let values: Vec<u32> = rand.iter_u32().take(10).collect();
+    let expected: Vec<u32> = (0..10).map(|counter| rand32(counter, KEY)).collect();
+    assert_eq!(values, expected);
+
+    let values: Vec<u64> = rand.iter_u64().take(10).collect();
+    let expected: Vec<u64> = (10..20).map(|counter| rand64(counter, KEY)).collect();
+    assert_eq!(values, expected);
+    assert_eq!(rand.counter(), 20);
+}
+
+#[test]
+fn verify_const_array() {
+    static TABLE32: [u32; 16] = squares_rnd::rand32_array(5, KEY);
+    static TABLE64: [u64; 16] = squares_rnd::rand64_array(u64::MAX - 1, KEY);
+
+    for (idx, value) in TABLE32.iter().enumerate() {
+        assert_eq!(*value, rand32(5 + idx as u64, KEY));
+    }
+    for (idx, value) in TABLE64.iter().enumerate() {
+        assert_eq!(*value, rand64((u64::MAX - 1).wrapping_add(idx as u64), KEY));
+    }
+
+    let empty: [u32; 0] = squares_rnd::rand32_array(0, KEY);
+    assert!(empty.is_empty());
+}
+
+#[cfg(feature = "getrandom")]
+#[test]
+fn verify_from_entropy() {
+    let first = Rand::from_entropy();
+    let second = Rand::from_entropy();
+    assert_ne!(first.counter(), second.counter());
+
+    let counter = first.counter();
+    assert_eq!(first.next_u64(), rand64(counter, KEY));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn verify_serde() {
+    let rand = Rand::new(KEY);
+    for _ in 0..10 {
+        rand.next_u64();
+    }
+
+    let state = serde_json::to_string(&rand).unwrap();
+    assert_eq!(state, format!("{{\"counter\":10,\"key\":{},\"initial\":0}}", KEY));
+
+    let restored: Rand = serde_json::from_str(&state).unwrap();
+    assert_eq!(restored.counter(), 10);
+    assert_eq!(restored.counters_consumed(), 10);
+
+    let legacy: Rand = serde_json::from_str(&format!("{{\"counter\":10,\"key\":{}}}", KEY)).unwrap();
+    assert_eq!(legacy, restored);
+    assert_eq!(legacy.counters_consumed(), 0);
+    for _ in 0..10 {
+        assert_eq!(restored.next_u64(), rand.next_u64());
+    }
+
+    //Ordering is not part of state
+    let relaxed = Rand::new(KEY).with_ordering::<squares_rnd::ordering::Relaxed>();
+    let restored: Rand = serde_json::from_str(&serde_json::to_string(&relaxed).unwrap()).unwrap();
+    assert_eq!(restored, Rand::new(KEY));
+}
+
+#[test]
+fn verify_small_unsigned() {
+    let rand = Rand::new(KEY);
+    assert_eq!(rand.next_u8(), (rand32(0, KEY) >> 24) as u8);
+    assert_eq!(rand.next_u16(), (rand32(1, KEY) >> 16) as u16);
+
+    let mut seen = [false; 256];
+    for _ in 0..10_000 {
+        seen[rand.next_u8() as usize] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+
+    let mut seen = vec![false; 65536];
+    for _ in 0..2_000_000 {
+        seen[rand.next_u16() as usize] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+}
+
+#[test]
+fn verify_hash_u64() {
+    const HASH: u64 = squares_rnd::hash_u64(1, KEY);
+    assert_eq!(HASH, 17501607969359464034);
+    for index in 0..100 {
+        assert_eq!(squares_rnd::hash_u64(index, KEY), rand64(index, KEY));
+    }
+}
+
+#[test]
+fn verify_reset() {
+    let rand = Rand::new(KEY);
+    let first: Vec<u64> = (0..10).map(|_| rand.next_u64()).collect();
+
+    rand.reset();
+    assert_eq!(rand.counter(), 0);
+    let second: Vec<u64> = (0..10).map(|_| rand.next_u64()).collect();
+    assert_eq!(first, second);
+
+    rand.reset_to(5);
+    assert_eq!(rand.counter(), 5);
+    assert_eq!(rand.next_u64(), first[5]);
+}
+
+#[test]
+fn verify_assert_good_key() {
+    use squares_rnd::assert_good_key;
+
+    const MY_KEY: u64 = assert_good_key(KEY);
+    assert_eq!(MY_KEY, KEY);
+}
+
+#[test]
+#[should_panic]
+fn verify_assert_bad_key() {
+    squares_rnd::assert_good_key(std::hint::black_box(0));
+}
+
+#[test]
+fn verify_try_new() {
+    use squares_rnd::{BadKey, is_good_key};
+
+    let rand = Rand::try_new(KEY).unwrap();
+    assert_eq!(rand.key(), KEY);
+    assert_eq!(rand.counter(), 0);
+
+    assert_eq!(Rand::try_new(0).unwrap_err(), BadKey::Unbalanced(0));
+    assert_eq!(Rand::try_new(u64::MAX).unwrap_err(), BadKey::Unbalanced(64));
+    assert_eq!(Rand::try_new(0x0000_0000_ffff_ffff).unwrap_err(), BadKey::LongRun(32));
+    for key in [KEY, 0, 1, 0x5555_5555_5555_5555, 0x5555_5555_5554_ff00].iter().copied() {
+        assert_eq!(Rand::try_new(key).is_ok(), is_good_key(key), "key={:x}", key);
+    }
+    assert_eq!(BadKey::Unbalanced(0).to_string(), "Key has 0 ones, but 28..=36 is required");
+}
+
+#[test]
+fn verify_key_quality() {
+    use squares_rnd::{is_good_key, key_balance};
+
+    assert_eq!(key_balance(KEY), 33);
+    assert!(is_good_key(KEY));
+    assert!(is_good_key(0x5555_5555_5555_5555));
+    assert!(is_good_key(0x0f0f_0f0f_0f0f_0f0f));
+
+    assert_eq!(key_balance(0), 0);
+    assert!(!is_good_key(0));
+    assert!(!is_good_key(u64::MAX));
+    assert!(!is_good_key(1));
+    //Balanced, but with long runs
+    assert_eq!(key_balance(0x0000_0000_ffff_ffff), 32);
+    assert!(!is_good_key(0x0000_0000_ffff_ffff));
+    assert!(!is_good_key(0x5555_5555_5555_fe00));
+    assert!(is_good_key(0x5555_5555_5554_ff00));
+}
+
+#[test]
+fn verify_generate_key() {
+    use squares_rnd::{is_good_key, generate_key};
+
+    let mut keys = std::collections::HashSet::new();
+    for seed in 0..100 {
+        let key = generate_key(seed);
+        assert!(is_good_key(key), "seed={} key={:x}", seed, key);
+        assert_eq!(key, generate_key(seed));
+        keys.insert(key);
+    }
+    assert!(keys.len() > 1);
+}
+
+#[test]
+fn verify_u128() {
+    use squares_rnd::rand128;
+
+    let rand = Rand::new(KEY);
+    let value = rand.next_u128();
+    assert_eq!(rand.counter(), 2);
+    assert_eq!(value, rand128(0, KEY));
+    assert_eq!((value >> 64) as u64, rand64(0, KEY));
+    assert_eq!(value as u64, rand64(1, KEY));
+    assert_eq!(rand.next_u128(), rand128(2, KEY));
+
+    let mut diff_ones = 0;
+    for counter in (0..10_000).step_by(2) {
+        let value = rand128(counter, KEY);
+        let high = (value >> 64) as u64;
+        let low = value as u64;
+        assert_ne!(high, low);
+        diff_ones += (high ^ low).count_ones() as u64;
+    }
+    //Uncorrelated halves differ in about half of bits
+    let average = diff_ones as f64 / 5_000.0;
+    assert!((average - 32.0).abs() < 0.5, "average={}", average);
+}
+
+#[test]
+fn verify_bytes() {
+    let rand = Rand::new(KEY);
+    let mut expected = [0u8; 21];
+    rand.fill_bytes(&mut expected);
+
+    let rand = Rand::new(KEY);
+    let bytes: Vec<u8> = rand.bytes().take(expected.len()).collect();
+    assert_eq!(bytes, expected);
+    assert_eq!(rand.counter(), 3);
+
+    let rand = Rand::new(KEY);
+    let mut bytes = rand.bytes();
+    bytes.next();
+    assert_eq!(rand.counter(), 1);
+    let _ = bytes.nth(6);
+    assert_eq!(rand.counter(), 1);
+    bytes.next();
+    assert_eq!(rand.counter(), 2);
+}
+
+#[test]
+fn verify_next_array() {
+    let rand = Rand::new(KEY);
+    let expected = Rand::new(KEY);
+
+    let rgba: [u32; 4] = rand.next_u32_array();
+    for value in rgba.iter() {
+        assert_eq!(*value, expected.next_u32());
+    }
+
+    let pair = rand.next_u64_array::<2>();
+    for value in pair.iter() {
+        assert_eq!(*value, expected.next_u64());
+    }
+    assert_eq!(rand.counter(), 6);
+}
+
+#[test]
+fn verify_choose_weighted() {
+    const SAMPLES: usize = 100_000;
+    let rand = Rand::new(KEY);
+
+    let empty: [(u8, u32); 0] = [];
+    assert!(rand.choose_weighted(&empty, |item| item.1).is_none());
+    assert!(rand.choose_weighted(&[(0, 0), (1, 0)], |item| item.1).is_none());
+    assert_eq!(rand.counter(), 0);
+
+    let items = [(0usize, 1u32), (1, 0), (2, 3), (3, 6)];
+    let mut counts = [0usize; 4];
+    for _ in 0..SAMPLES {
+        let item = rand.choose_weighted(&items, |item| item.1).unwrap();
+        counts[item.0] += 1;
+    }
+
+    assert_eq!(counts[1], 0);
+    for (item, count) in items.iter().zip(counts.iter()) {
+        let expected = item.1 as f64 / 10.0;
+        let actual = *count as f64 / SAMPLES as f64;
+        assert!((expected - actual).abs() < 0.01, "item={:?} actual={}", item, actual);
+    }
+}
+
+#[test]
+fn verify_choose_weighted_index() {
+    let weights = [1u64, 0, 3, 6, 0, 2];
+    let cumulative: Vec<u64> = weights.iter().scan(0, |sum, weight| {
+        *sum += weight;
+        Some(*sum)
+    }).collect();
+
+    let rand = Rand::new(KEY);
+    let expected = Rand::new(KEY);
+    for _ in 0..10_000 {
+        let idx = rand.choose_weighted_index(&cumulative);
+
+        let mut target = expected.next_u64_up(12);
+        let mut expected_idx = 0;
+        while target >= weights[expected_idx] {
+            target -= weights[expected_idx];
+            expected_idx += 1;
+        }
+        assert_eq!(idx, expected_idx);
+        assert_ne!(weights[idx], 0);
+    }
+
+    assert_eq!(rand.choose_weighted_index(&[0, 0, 5]), 2);
+}
+
+#[test]
+#[should_panic]
+fn verify_choose_weighted_index_zero_total() {
+    Rand::new(KEY).choose_weighted_index(&[0, 0]);
+}
+
+#[test]
+fn verify_relaxed() {
+    use squares_rnd::ordering::Relaxed;
+
+    let rand = Rand::new(KEY).with_ordering::<Relaxed>();
+    let expected = Rand::new(KEY);
+    for _ in 0..100 {
+        assert_eq!(rand.next_u32(), expected.next_u32());
+        assert_eq!(rand.next_u64(), expected.next_u64());
+    }
+    assert_eq!(rand.counter(), 200);
+
+    let rand = std::sync::Arc::new(Rand::new(KEY).with_ordering::<Relaxed>());
+    let workers: Vec<_> = (0..4).map(|_| {
+        let rand = rand.clone();
+        std::thread::spawn(move || (0..1000).map(|_| rand.next_u64()).collect::<Vec<_>>())
+    }).collect();
+
+    let mut values = std::collections::HashSet::new();
+    for worker in workers {
+        values.extend(worker.join().unwrap());
+    }
+    assert_eq!(values.len(), 4000);
+    assert_eq!(rand.counter(), 4000);
+}
+
+#[test]
+fn verify_ordering() {
+    use std::sync::atomic::Ordering;
+    use squares_rnd::ordering::{AcqRel, OrderingPolicy, Relaxed, SeqCst};
+
+    fn check<O: OrderingPolicy>(rand: Rand<O>, ordering: Ordering) {
+        assert_eq!(rand.ordering(), ordering);
+
+        let expected = Rand::new(KEY);
+        let mut values = std::collections::HashSet::new();
+        for _ in 0..1000 {
+            let value = rand.next_u64();
+            assert_eq!(value, expected.next_u64());
+            assert!(values.insert(value));
+        }
+        assert_eq!(rand.counter(), 1000);
+        assert_eq!(rand.gen_range(0..10u32), expected.gen_range(0..10u32));
+        assert_eq!(rand.random::<u64>(), expected.random::<u64>());
+        rand.reset_to(5);
+        assert_eq!(rand.set_counter(10), 5);
+        assert_eq!(rand.clone().ordering(), ordering);
+        assert!(rand.split(2).all(|child| child.ordering() == ordering));
+    }
+
+    check(Rand::new(KEY), Ordering::AcqRel);
+    check(Rand::new(KEY).with_ordering::<Relaxed>(), Ordering::Relaxed);
+    check(Rand::new(KEY).with_ordering::<AcqRel>(), Ordering::AcqRel);
+    check(Rand::new(KEY).with_ordering::<SeqCst>(), Ordering::SeqCst);
+
+    //Ordering has no runtime representation
+    assert_eq!(core::mem::size_of::<Rand>(), 3 * core::mem::size_of::<u64>());
+    assert_eq!(core::mem::size_of::<Rand<Relaxed>>(), core::mem::size_of::<Rand>());
+
+    static RELAXED: Rand<Relaxed> = Rand::new(KEY).with_ordering::<Relaxed>();
+    assert_eq!(RELAXED.key(), KEY);
+}
+
+#[test]
+fn verify_counter_wrap() {
+    let rand = Rand::with_counter(u64::MAX, KEY);
+    assert_eq!(rand.next_u32(), rand32(u64::MAX, KEY));
+    assert_eq!(rand.counter(), 0);
+    assert_eq!(rand.next_u32(), rand32(0, KEY));
+
+    let rand = Rand::with_counter(u64::MAX - 1, KEY);
+    assert_eq!(rand.try_next_u32(), Some(rand32(u64::MAX - 1, KEY)));
+    assert_eq!(rand.counter(), u64::MAX);
+    assert_eq!(rand.try_next_u32(), None);
+    assert_eq!(rand.try_next_u64(), None);
+    assert_eq!(rand.counter(), u64::MAX);
+
+    rand.reset();
+    assert_eq!(rand.try_next_u64(), Some(rand64(0, KEY)));
+    assert_eq!(rand.counter(), 1);
+}
+
+#[test]
+fn verify_nonzero() {
+    let rand = Rand::new(KEY);
+    let expected = Rand::new(KEY);
+    for _ in 0..100_000 {
+        assert_eq!(rand.next_nonzero_u32().get(), expected.next_u32());
+        assert_eq!(rand.next_nonzero_u64().get(), expected.next_u64());
+    }
+}
+
+#[test]
+fn verify_counters_consumed() {
+    assert_eq!(Rand::PERIOD, u64::MAX);
+
+    let rand = Rand::with_counter(u64::MAX - 10, KEY);
+    assert_eq!(rand.counters_consumed(), 0);
+    for _ in 0..100 {
+        rand.next_u32();
+    }
+    assert_eq!(rand.counters_consumed(), 100);
+
+    rand.next_u128();
+    rand.skip(8);
+    assert_eq!(rand.counters_consumed(), 110);
+    assert_eq!(rand.clone().counters_consumed(), 110);
+
+    rand.reset_to(u64::MAX - 10);
+    assert_eq!(rand.counters_consumed(), 0);
+}
+
+#[test]
+fn verify_peek() {
+    let rand = Rand::new(KEY);
+    let value = rand.peek_u32();
+    assert_eq!(rand.peek_u32(), value);
+    assert_eq!(rand.counter(), 0);
+    assert_eq!(rand.next_u32(), value);
+
+    let value = rand.peek_u64();
+    assert_eq!(rand.counter(), 1);
+    assert_eq!(rand.next_u64(), value);
+}
+
+#[test]
+fn verify_nth() {
+    let rand = Rand::new(KEY);
+    for counter in [0, 1, 1000, u64::MAX].iter().copied() {
+        let value = rand.nth_u32(counter);
+        rand.set_counter(counter);
+        assert_eq!(rand.next_u32(), value);
+
+        let value = rand.nth_u64(counter);
+        rand.set_counter(counter);
+        assert_eq!(rand.next_u64(), value);
+    }
+}
+
+#[test]
+fn verify_at_coords() {
+    let rand = Rand::new(KEY);
+    let mut values = std::collections::HashSet::new();
+    for x in -16..16 {
+        for y in -16..16 {
+            let value = rand.at_coords(x, y);
+            assert_eq!(rand.at_coords(x, y), value);
+            assert!(values.insert(value));
+            if x != y {
+                assert_ne!(rand.at_coords(y, x), value);
+            }
+        }
+    }
+    assert_eq!(rand.counter(), 0);
+    assert_eq!(Rand::with_counter(100, KEY).at_coords(3, 7), rand.at_coords(3, 7));
+    assert_ne!(Rand::new(1).at_coords(3, 7), rand.at_coords(3, 7));
+}
+
+#[test]
+fn verify_next_u32_up_fast() {
+    let rand = Rand::new(KEY);
+    let expected = Rand::new(KEY);
+    for to in [1, 2, 3, 10, 1000, u32::MAX / 2 + 2, u32::MAX].iter().copied() {
+        for _ in 0..10_000 {
+            let value = rand.next_u32_up_fast(to);
+            assert!(value < to, "to={} value={}", to, value);
+            assert_eq!(value as u64, (expected.next_u32() as u64 * to as u64) >> 32);
+        }
+    }
+    assert_eq!(rand.counter(), 70_000);
+
+    //Empty range
+    assert_eq!(rand.next_u32_up_fast(0), 0);
+    assert_eq!(rand.counter(), 70_001);
+}
+
+#[test]
+fn verify_next_pair_up() {
+    let rand = Rand::new(KEY);
+    let mut seen = [[false; 5]; 7];
+    for _ in 0..10_000 {
+        let (x, y) = rand.next_pair_up(7, 5);
+        assert!(x < 7 && y < 5, "x={} y={}", x, y);
+        seen[x as usize][y as usize] = true;
+    }
+    assert!(seen.iter().all(|row| row.iter().all(|seen| *seen)));
+    assert_eq!(rand.counter(), 10_000);
+
+    for _ in 0..1000 {
+        let (x, y) = rand.next_pair_up(u32::MAX, 1);
+        assert!(x < u32::MAX);
+        assert_eq!(y, 0);
+    }
+    assert_eq!(rand.next_pair_up(0, 0), (0, 0));
+    assert_eq!(rand.next_pair_up(0, 5).0, 0);
+    assert_eq!(rand.next_pair_up(7, 0).1, 0);
+
+    let value = rand64(rand.counter(), KEY);
+    assert_eq!(rand.next_pair_up(u32::MAX, u32::MAX).0, (((value >> 32) * u32::MAX as u64) >> 32) as u32);
+}
+
+#[test]
+fn verify_next_u32_up_stats() {
+    let rand = Rand::new(KEY);
+    let expected = Rand::new(KEY);
+    let mut total_rejects = 0;
+    for to in [3, 10, 1000, u32::MAX / 2 + 2].iter().copied() {
+        for _ in 0..10_000 {
+            let (value, rejects) = rand.next_u32_up_stats(to);
+            assert_eq!(value, expected.next_u32_up(to));
+            assert_eq!(rand.counter(), expected.counter());
+            total_rejects += rejects as u64;
+        }
+    }
+    assert_eq!(rand.counter(), 40_000 + total_rejects);
+    //Almost half of draws is rejected with 2^31 + 2
+    assert!(total_rejects > 4000, "total_rejects={}", total_rejects);
+
+    for shift in 0..32 {
+        for _ in 0..1000 {
+            let (value, rejects) = rand.next_u32_up_stats(1 << shift);
+            assert!(value < 1 << shift);
+            assert_eq!(rejects, 0);
+        }
+    }
+}
+
+#[test]
+fn verify_next_u64_clamped() {
+    let rand = Rand::new(KEY);
+    for _ in 0..10_000 {
+        let value = rand.next_u64_clamped(10, 20);
+        assert!((10..=20).contains(&value), "value={}", value);
+
+        let value = rand.next_u64_clamped(20, 10);
+        assert!((10..=20).contains(&value), "value={}", value);
+
+        assert!(rand.next_u64_clamped(u64::MAX, u64::MAX - 1) >= u64::MAX - 1);
+    }
+    assert_eq!(rand.counter(), 30_000);
+
+    assert_eq!(rand.next_u64_clamped(7, 7), 7);
+    let expected = rand64(rand.counter(), KEY);
+    assert_eq!(rand.next_u64_clamped(u64::MAX, 0), expected);
+
+    let rand = Rand::new(KEY);
+    let reversed = Rand::new(KEY);
+    for _ in 0..100 {
+        assert_eq!(rand.next_u64_clamped(0, 1000), reversed.next_u64_clamped(1000, 0));
+    }
+}
+
+#[test]
+fn verify_gen_index() {
+    let rand = Rand::new(KEY);
+    let mut seen = [false; 10];
+    for _ in 0..1000 {
+        seen[rand.gen_index(seen.len())] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+    assert_eq!(rand.gen_index(1), 0);
+}
+
+#[test]
+#[should_panic]
+fn verify_gen_index_zero() {
+    let rand = Rand::new(KEY);
+    rand.gen_index(0);
+}
+
+#[test]
+fn verify_clone() {
+    let rand = Rand::new(KEY);
+    rand.next_u64();
+
+    let cloned = rand.clone();
+    assert_eq!(cloned.counter(), 1);
+    for _ in 0..10 {
+        assert_eq!(rand.next_u64(), cloned.next_u64());
+    }
+
+    cloned.next_u64();
+    assert_eq!(rand.counter(), 11);
+    assert_eq!(cloned.counter(), 12);
+    assert_ne!(rand.next_u64(), cloned.next_u64());
+}
+
+#[test]
+fn verify_fill_integers() {
+    let rand = Rand::new(KEY);
+    let expected = Rand::new(KEY);
+
+    for len in [0, 1, 3, 4, 7, 16].iter().copied() {
+        let mut buffer = vec![0u32; len];
+        rand.fill_u32(&mut buffer);
+        for value in buffer {
+            assert_eq!(value, expected.next_u32());
+        }
+
+        let mut buffer = vec![0u64; len];
+        rand.fill_u64(&mut buffer);
+        for value in buffer {
+            assert_eq!(value, expected.next_u64());
+        }
+    }
+    assert_eq!(rand.counter(), expected.counter());
+}
+
+#[test]
+fn verify_generate_u64() {
+    let rand = Rand::with_counter(u64::MAX - 2, KEY);
+    let expected = Rand::with_counter(u64::MAX - 2, KEY);
+
+    for count in [0, 1, 5, 16].iter().copied() {
+        let mut calls = 0;
+        rand.generate_u64(count, |value| {
+            assert_eq!(value, expected.next_u64());
+            calls += 1;
+        });
+        assert_eq!(calls, count);
+    }
+    assert_eq!(rand.counter(), expected.counter());
+}
+
+#[test]
+fn verify_bit_stream() {
+    const SAMPLES: usize = 640_000;
+    let rand = Rand::new(KEY);
+    let mut bits = rand.bit_stream();
+
+    let first = rand64(0, KEY);
+    for idx in 0..64 {
+        assert_eq!(bits.next_bit(), (first >> (63 - idx)) & 1 == 1);
+    }
+    assert_eq!(rand.counter(), 1);
+
+    let second = rand64(1, KEY);
+    assert_eq!(bits.next_bit(), second >> 63 == 1);
+    assert_eq!(rand.counter(), 2);
+
+    let ones = bits.take(SAMPLES).filter(|bit| *bit).count();
+    let ratio = ones as f64 / SAMPLES as f64;
+    assert!((ratio - 0.5).abs() < 0.005, "ratio={}", ratio);
+    assert_eq!(rand.counter(), 2 + SAMPLES as u64 / 64);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn verify_choose_multiple() {
+    use std::collections::HashSet;
+
+    let items: Vec<u32> = (0..50).collect();
+    let rand = Rand::new(KEY);
+    let chosen = rand.choose_multiple(&items, 10);
+    assert_eq!(chosen.len(), 10);
+    assert_eq!(chosen.iter().collect::<HashSet<_>>().len(), 10);
+    assert!(chosen.iter().all(|item| items.contains(item)));
+
+    let rand = Rand::new(KEY);
+    assert_eq!(rand.choose_multiple(&items, 10), chosen);
+
+    let mut all = rand.choose_multiple(&items, 100);
+    assert_eq!(all.len(), items.len());
+    all.sort_unstable();
+    assert_eq!(all, items);
+
+    assert!(rand.choose_multiple(&items, 0).is_empty());
+    assert!(rand.choose_multiple::<u32>(&[], 5).is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_weighted_reservoir() {
+    let items = [(0usize, 1.0), (1, 2.0), (2, 0.0), (3, 4.0), (4, -1.0), (5, 8.0)];
+
+    let rand = Rand::new(KEY);
+    let sample = rand.weighted_reservoir(items.iter().copied(), 10);
+    assert_eq!(rand.counter(), 4);
+    let mut sorted = sample.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, [0, 1, 3, 5]);
+
+    let rand = Rand::new(KEY);
+    let mut counts = [0usize; 6];
+    for _ in 0..10_000 {
+        let sample = rand.weighted_reservoir(items.iter().copied(), 2);
+        assert_eq!(sample.len(), 2);
+        assert_ne!(sample[0], sample[1]);
+        for item in sample {
+            counts[item] += 1;
+        }
+    }
+    assert_eq!(counts[2], 0);
+    assert_eq!(counts[4], 0);
+    assert!(counts[0] < counts[1] && counts[1] < counts[3] && counts[3] < counts[5], "counts={:?}", counts);
+
+    let rand = Rand::new(KEY);
+    assert!(rand.weighted_reservoir(items.iter().copied(), 0).is_empty());
+    assert_eq!(rand.counter(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_weighted_permutation() {
+    let items = vec![(0usize, 1.0), (1, 0.0), (2, 2.0), (3, -1.0), (4, 8.0)];
+
+    let rand = Rand::new(KEY);
+    let order = rand.weighted_permutation(items.clone());
+    assert_eq!(rand.counter(), 3);
+    assert_eq!(order.len(), 5);
+    assert_eq!(order[3..], [1, 3]);
+    let mut sorted = order.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, [0, 1, 2, 3, 4]);
+
+    assert_eq!(Rand::new(KEY).weighted_permutation(items.clone()), order);
+
+    //Average position must follow weights
+    let mut positions = [0usize; 5];
+    let mut first = [0usize; 5];
+    for _ in 0..10_000 {
+        let order = rand.weighted_permutation(items.clone());
+        first[order[0]] += 1;
+        for (position, item) in order.iter().enumerate() {
+            positions[*item] += position;
+        }
+    }
+    assert!(positions[4] < positions[2] && positions[2] < positions[0], "positions={:?}", positions);
+    //First pick is proportional to weight: 8 / 11
+    let ratio = first[4] as f64 / 10_000.0;
+    assert!((ratio - 8.0 / 11.0).abs() < 0.015, "ratio={}", ratio);
+
+    assert!(rand.weighted_permutation(Vec::<(u8, f64)>::new()).is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn verify_multinomial() {
+    let rand = Rand::new(KEY);
+    let weights = [1, 0, 3, 6];
+    let counts = rand.multinomial(100_000, &weights);
+    assert_eq!(counts.len(), weights.len());
+    assert_eq!(counts.iter().sum::<u64>(), 100_000);
+    assert_eq!(counts[1], 0);
+    for (count, weight) in counts.iter().zip(weights.iter()) {
+        let ratio = *count as f64 / 100_000.0;
+        let expected = *weight as f64 / 10.0;
+        assert!((ratio - expected).abs() < 0.01, "ratio={} expected={}", ratio, expected);
+    }
+
+    assert_eq!(rand.multinomial(0, &weights), [0, 0, 0, 0]);
+    assert_eq!(rand.multinomial(10, &[0, 5, 0]), [0, 10, 0]);
+    for n in [1, 7, 100].iter().copied() {
+        assert_eq!(rand.multinomial(n, &[2, 2, 2, 2, 2]).iter().sum::<u64>(), n);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic]
+fn verify_multinomial_zero_weights() {
+    Rand::new(KEY).multinomial(10, &[0, 0]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn verify_reservoir_sample() {
+    let rand = Rand::new(KEY);
+    let sample = rand.reservoir_sample(0..1000u32, 10);
+    assert_eq!(sample.len(), 10);
+    assert_eq!(rand.counter(), 990);
+
+    let rand = Rand::new(KEY);
+    assert_eq!(rand.reservoir_sample(0..1000u32, 10), sample);
+
+    assert_eq!(rand.reservoir_sample(0..5u32, 10), [0, 1, 2, 3, 4]);
+    assert!(rand.reservoir_sample(0..5u32, 0).is_empty());
+
+    //Each element is expected to be picked with probability 1/10
+    let mut counts = [0usize; 20];
+    for _ in 0..10_000 {
+        for item in rand.reservoir_sample(0..20usize, 2) {
+            counts[item] += 1;
+        }
+    }
+    for count in counts.iter() {
+        let ratio = *count as f64 / 10_000.0;
+        assert!((ratio - 0.1).abs() < 0.015, "ratio={}", ratio);
+    }
+}
+
+#[test]
+fn verify_from_key() {
+    let rand: Rand = KEY.into();
+    let expected = Rand::new(KEY);
+    assert_eq!(rand.counter(), 0);
+    for _ in 0..10 {
+        assert_eq!(rand.next_u64(), expected.next_u64());
+    }
+}
+
+#[test]
+fn verify_with_key() {
+    const NEW_KEY: u64 = 0x5555_5555_5555_5555;
+
+    let rand = Rand::new(KEY);
+    assert_eq!(rand.key(), KEY);
+    rand.next_u64();
+
+    let rand = rand.with_key(NEW_KEY);
+    assert_eq!(rand.key(), NEW_KEY);
+    assert_eq!(rand.counter(), 1);
+    let value = rand.next_u64();
+    assert_eq!(value, rand64(1, NEW_KEY));
+    assert_ne!(value, rand64(1, KEY));
+}
+
+#[test]
+fn verify_gen_range() {
+    let rand = Rand::new(KEY);
+    for _ in 0..10_000 {
+        assert!((5..10).contains(&rand.gen_range(5u32..10)));
+        assert!((5..=10).contains(&rand.gen_range(5u32..=10)));
+        assert!((5..10).contains(&rand.gen_range(5u64..10)));
+        assert!((5..=10).contains(&rand.gen_range(5u64..=10)));
+        assert!((-10..-5).contains(&rand.gen_range(-10i32..-5)));
+        assert!((-10..=-5).contains(&rand.gen_range(-10i32..=-5)));
+        assert!((-10..5).contains(&rand.gen_range(-10i64..5)));
+        assert!((-10..=5).contains(&rand.gen_range(-10i64..=5)));
+
+        rand.gen_range(0..=u32::MAX);
+        rand.gen_range(0..=u64::MAX);
+        rand.gen_range(i32::MIN..=i32::MAX);
+        rand.gen_range(i64::MIN..=i64::MAX);
+    }
+
+    assert_eq!(rand.gen_range(3u32..=3), 3);
+    assert_eq!(rand.gen_range(-3i64..-2), -3);
+    assert_eq!(rand.gen_range(i32::MAX..=i32::MAX), i32::MAX);
+
+    let mut seen = [false; 11];
+    for _ in 0..1000 {
+        seen[(rand.gen_range(-5i32..=5) + 5) as usize] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+}
+
+#[test]
+#[should_panic(expected = "Cannot sample empty range")]
+fn verify_gen_range_empty() {
+    let rand = Rand::new(KEY);
+    rand.gen_range(5u64..5);
+}
+
+#[test]
+#[should_panic(expected = "Cannot sample empty range")]
+#[allow(clippy::reversed_empty_ranges)]
+fn verify_gen_range_inclusive_empty() {
+    let rand = Rand::new(KEY);
+    rand.gen_range(5i32..=4);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_thread_rand() {
+    use squares_rnd::{seed_thread, random_u32, random_u64};
+
+    assert_ne!(random_u64(), random_u64());
+
+    seed_thread(10, KEY);
+    assert_eq!(random_u32(), rand32(10, KEY));
+    assert_eq!(random_u64(), rand64(11, KEY));
+
+    std::thread::spawn(|| {
+        seed_thread(0, KEY);
+        assert_eq!(random_u64(), rand64(0, KEY));
+    }).join().unwrap();
+    assert_eq!(random_u64(), rand64(12, KEY));
+}
+
+#[test]
+fn verify_in_unit_circle() {
+    const SAMPLES: u64 = 100_000;
+    let rand = Rand::new(KEY);
+    let mut quadrants = [0u64; 4];
+    for _ in 0..SAMPLES {
+        let (x, y) = rand.in_unit_circle();
+        assert!(x * x + y * y < 1.0, "x={} y={}", x, y);
+        quadrants[(x < 0.0) as usize * 2 + (y < 0.0) as usize] += 1;
+    }
+
+    for count in quadrants.iter() {
+        let ratio = *count as f64 / SAMPLES as f64;
+        assert!((ratio - 0.25).abs() < 0.01, "ratio={}", ratio);
+    }
+    let attempts = rand.counter() as f64 / 2.0 / SAMPLES as f64;
+    assert!((attempts - 4.0 / std::f64::consts::PI).abs() < 0.01, "attempts={}", attempts);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_unit_vector() {
+    let rand = Rand::new(KEY);
+    let mut sum = (0.0, 0.0, 0.0);
+    for _ in 0..100_000 {
+        let (x, y) = rand.unit_vector_2d();
+        assert!(((x * x + y * y).sqrt() - 1.0).abs() < 1e-12, "x={} y={}", x, y);
+
+        let (x, y, z) = rand.unit_vector_3d();
+        assert!(((x * x + y * y + z * z).sqrt() - 1.0).abs() < 1e-12, "x={} y={} z={}", x, y, z);
+        sum.0 += x;
+        sum.1 += y;
+        sum.2 += z;
+    }
+    //Uniform directions cancel out
+    assert!(sum.0.abs() / 100_000.0 < 0.01);
+    assert!(sum.1.abs() / 100_000.0 < 0.01);
+    assert!(sum.2.abs() / 100_000.0 < 0.01);
+}
+
+#[test]
+fn verify_eq_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash(rand: &Rand) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        rand.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let first = Rand::with_counter(5, KEY);
+    let second = Rand::with_counter(5, KEY);
+    assert_eq!(first, second);
+    assert_eq!(hash(&first), hash(&second));
+
+    first.next_u32();
+    assert_ne!(first, second);
+    second.next_u64();
+    assert_eq!(first, second);
+
+    assert_ne!(Rand::with_counter(5, KEY), Rand::with_counter(5, 1));
+}
+
+#[test]
+fn verify_roll() {
+    let rand = Rand::new(KEY);
+    let mut seen = [false; 6];
+    for _ in 0..1000 {
+        let value = rand.roll(6);
+        assert!((1..=6).contains(&value), "value={}", value);
+        seen[value as usize - 1] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+    assert_eq!(rand.roll(1), 1);
+
+    let mut seen = [false; 19];
+    for _ in 0..10_000 {
+        let value = rand.roll_dice(3, 6);
+        assert!((3..=18).contains(&value), "value={}", value);
+        seen[value as usize] = true;
+    }
+    assert!(seen[3..].iter().all(|seen| *seen));
+    assert_eq!(rand.roll_dice(0, 6), 0);
+}
+
+#[test]
+#[should_panic]
+fn verify_roll_zero_sides() {
+    let rand = Rand::new(KEY);
+    rand.roll(0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn verify_strings() {
+    let rand = Rand::new(KEY);
+    let hex = rand.hex_string(13);
+    assert_eq!(hex.len(), 26);
+    assert!(hex.bytes().all(|ch| ch.is_ascii_digit() || (b'a'..=b'f').contains(&ch)), "hex={}", hex);
+    assert_eq!(&hex[..16], format!("{:016x}", rand64(0, KEY).swap_bytes()));
+    assert!(rand.hex_string(0).is_empty());
+
+    let text = rand.alphanumeric_string(1000);
+    assert_eq!(text.len(), 1000);
+    assert!(text.bytes().all(|ch| ch.is_ascii_alphanumeric()), "text={}", text);
+    assert!(text.bytes().any(|ch| ch.is_ascii_digit()));
+    assert!(text.bytes().any(|ch| ch.is_ascii_lowercase()));
+    assert!(text.bytes().any(|ch| ch.is_ascii_uppercase()));
+}
+
+#[test]
+fn verify_ascii_printable() {
+    let rand = Rand::new(KEY);
+    let mut seen = [false; 0x7F];
+    for _ in 0..10_000 {
+        let byte = rand.ascii_printable();
+        assert!((0x20..=0x7E).contains(&byte), "byte={:x}", byte);
+        seen[byte as usize] = true;
+    }
+    assert!(seen[0x20..].iter().all(|seen| *seen));
+    assert_eq!(rand.counter(), 10_000);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn verify_ascii_printable_string() {
+    let rand = Rand::new(KEY);
+    let text = rand.ascii_printable_string(1000);
+    assert_eq!(text.len(), 1000);
+    assert!(text.bytes().all(|ch| ch == b' ' || ch.is_ascii_graphic()), "text={}", text);
+    assert!(rand.ascii_printable_string(0).is_empty());
+
+    let expected = Rand::new(KEY);
+    assert!(text.bytes().all(|ch| ch == expected.ascii_printable()));
+}
+
+#[test]
+fn verify_uuid_v4() {
+    let rand = Rand::new(KEY);
+    for _ in 0..1000 {
+        let uuid = rand.uuid_v4();
+        assert_eq!(uuid[6] >> 4, 4);
+        assert_eq!(uuid[8] >> 6, 0b10);
+    }
+    assert_eq!(rand.counter(), 2000);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn verify_uuid_v4_string() {
+    let rand = Rand::new(KEY);
+    let uuid = rand.uuid_v4_string();
+    assert_eq!(uuid.len(), 36);
+
+    let parts: Vec<&str> = uuid.split('-').collect();
+    assert_eq!(parts.iter().map(|part| part.len()).collect::<Vec<_>>(), [8, 4, 4, 4, 12]);
+    assert!(parts[2].starts_with('4'));
+    assert!(parts[3].starts_with(|ch| "89ab".contains(ch)));
+
+    let rand = Rand::new(KEY);
+    let bytes = rand.uuid_v4();
+    let expected: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    assert_eq!(uuid.replace('-', ""), expected);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn verify_par_fill_u64() {
+    let rand = Rand::with_counter(u64::MAX - 5000, KEY);
+    let expected = Rand::with_counter(u64::MAX - 5000, KEY);
+
+    for len in [0, 1, 4095, 4096, 4097, 100_000].iter().copied() {
+        let mut parallel = vec![0u64; len];
+        rand.par_fill_u64(&mut parallel);
+
+        let mut sequential = vec![0u64; len];
+        expected.fill_u64(&mut sequential);
+        assert_eq!(parallel, sequential);
+    }
+    assert_eq!(rand.counter(), expected.counter());
+}
+
+#[test]
+fn verify_gen_char() {
+    let rand = Rand::new(KEY);
+    assert_eq!(rand.gen_char('x', 'x'), 'x');
+
+    let mut seen = [false; 26];
+    for _ in 0..1000 {
+        let value = rand.gen_char('a', 'z');
+        assert!(value.is_ascii_lowercase(), "value={}", value);
+        seen[(value as u8 - b'a') as usize] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+
+    let low = '\u{D7FE}';
+    let high = '\u{E001}';
+    let mut seen = [false; 4];
+    for _ in 0..1000 {
+        let value = rand.gen_char(low, high) as u32;
+        assert!(!(0xD800..=0xDFFF).contains(&value), "value={:x}", value);
+        seen[match value {
+            0xD7FE => 0,
+            0xD7FF => 1,
+            0xE000 => 2,
+            0xE001 => 3,
+            value => panic!("Out of range: {:x}", value),
+        }] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+
+    for _ in 0..10_000 {
+        let value = rand.gen_char('\0', char::MAX) as u32;
+        assert!(!(0xD800..=0xDFFF).contains(&value), "value={:x}", value);
+    }
+}
+
+#[test]
+#[should_panic]
+fn verify_gen_char_empty() {
+    let rand = Rand::new(KEY);
+    rand.gen_char('b', 'a');
+}
+
+#[test]
+fn verify_reproducibility() {
+    //Outputs of rand32 and rand64 are pinned by verify_u32 and verify_u64, bytes must be little-endian
+    let mut bytes = [0u8; 8 * EXPECTED_U64.len()];
+    Rand::new(KEY).fill_bytes(&mut bytes);
+    for (chunk, expected) in bytes.chunks(8).zip(EXPECTED_U64.iter()) {
+        assert_eq!(chunk, expected.to_le_bytes());
+    }
+}
+
+#[test]
+fn verify_skip() {
+    let rand = Rand::new(KEY);
+    let expected = Rand::new(KEY);
+
+    rand.skip(3);
+    for _ in 0..3 {
+        expected.next_u32();
+    }
+    assert_eq!(rand.counter(), 3);
+    assert_eq!(rand.next_u32(), expected.next_u32());
+
+    rand.skip(0);
+    assert_eq!(rand.counter(), 4);
+}
+
+#[test]
+fn verify_f64_open() {
+    const SAMPLES: usize = 300_000;
+    let rand = Rand::new(KEY);
+    let mut buckets = [0usize; 10];
+    for _ in 0..SAMPLES {
+        let value = rand.next_f64_open();
+        assert!(value > 0.0 && value < 1.0, "value={}", value);
+        buckets[(value * 10.0) as usize] += 1;
+    }
+
+    for count in buckets.iter() {
+        let ratio = *count as f64 / SAMPLES as f64;
+        assert!((ratio - 0.1).abs() < 0.005, "ratio={}", ratio);
+    }
+}
+
+#[test]
+fn verify_full_iter() {
+    let rand = Rand::with_counter(10, KEY);
+    let mut last = None;
+    for res in rand.full_iter_u32().take(100) {
+        if let Some(last) = last {
+            assert!(res.counter > last);
+        }
+        assert_eq!(res.value, rand.nth_u32(res.counter));
+        last = Some(res.counter);
+    }
+    assert_eq!(last, Some(109));
+
+    for (idx, res) in rand.full_iter_u64().take(10).enumerate() {
+        assert_eq!(res.counter, 110 + idx as u64);
+        assert_eq!(res.value, rand.nth_u64(res.counter));
+    }
+}
+
+#[test]
+fn verify_weighted_bool() {
+    const SAMPLES: usize = 100_000;
+    let rand = Rand::new(KEY);
+
+    for (numerator, denominator) in [(1, 2), (1, 10), (7, 9)].iter().copied() {
+        let count = (0..SAMPLES).filter(|_| rand.weighted_bool(numerator, denominator)).count();
+        let ratio = count as f64 / SAMPLES as f64;
+        let expected = numerator as f64 / denominator as f64;
+        assert!((ratio - expected).abs() < 0.01, "ratio={} expected={}", ratio, expected);
+    }
+
+    let counter = rand.counter();
+    assert!(rand.weighted_bool(5, 5));
+    assert!(rand.weighted_bool(6, 5));
+    assert_eq!(rand.counter(), counter);
+    assert!((0..1000).all(|_| !rand.weighted_bool(0, 5)));
+}
+
+#[test]
+#[should_panic]
+fn verify_weighted_bool_zero_denominator() {
+    let rand = Rand::new(KEY);
+    rand.weighted_bool(1, 0);
+}
+
+#[test]
+fn verify_random() {
+    let rand = Rand::new(KEY);
+    let expected = Rand::new(KEY);
+
+    assert_eq!(rand.random::<u8>(), expected.next_u8());
+    assert_eq!(rand.random::<u16>(), expected.next_u16());
+    assert_eq!(rand.random::<u32>(), expected.next_u32());
+    assert_eq!(rand.random::<u64>(), expected.next_u64());
+    assert_eq!(rand.random::<u128>(), expected.next_u128());
+    assert_eq!(rand.random::<i8>(), expected.next_u8() as i8);
+    assert_eq!(rand.random::<i16>(), expected.next_u16() as i16);
+    assert_eq!(rand.random::<i32>(), expected.next_i32());
+    assert_eq!(rand.random::<i64>(), expected.next_i64());
+    assert_eq!(rand.random::<i128>(), expected.next_u128() as i128);
+    assert_eq!(rand.random::<bool>(), expected.next_bool());
+    assert_eq!(rand.random::<f32>(), expected.next_f32());
+    assert_eq!(rand.random::<f64>(), expected.next_f64());
+    assert_eq!(rand.counter(), expected.counter());
+
+    let value: f64 = rand.random();
+    assert!((0.0..1.0).contains(&value));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn verify_sample_indices() {
+    let rand = Rand::new(KEY);
+    let indices = rand.sample_indices(100, 10);
+    assert_eq!(indices.len(), 10);
+    assert!(rand.counter() >= 10);
+    assert!(indices.windows(2).all(|pair| pair[0] < pair[1]), "indices={:?}", indices);
+    assert!(indices.iter().all(|idx| *idx < 100));
+
+    let rand = Rand::new(KEY);
+    assert_eq!(rand.sample_indices(100, 10), indices);
+
+    assert_eq!(rand.sample_indices(5, 5), [0, 1, 2, 3, 4]);
+    assert!(rand.sample_indices(5, 0).is_empty());
+
+    //Each index is expected to be picked with probability 1/10
+    let mut counts = [0usize; 20];
+    for _ in 0..10_000 {
+        for idx in rand.sample_indices(20, 2) {
+            counts[idx] += 1;
+        }
+    }
+    for count in counts.iter() {
+        let ratio = *count as f64 / 10_000.0;
+        assert!((ratio - 0.1).abs() < 0.015, "ratio={}", ratio);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic]
+fn verify_sample_indices_too_many() {
+    let rand = Rand::new(KEY);
+    rand.sample_indices(5, 6);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn verify_zeroize() {
+    use zeroize::Zeroize;
+
+    let mut rand = Rand::with_counter(100, KEY);
+    rand.next_u64();
+    rand.zeroize();
+    assert_eq!(rand.key(), 0);
+    assert_eq!(rand.counter(), 0);
+
+    fn assert_zeroize_on_drop<T: zeroize::ZeroizeOnDrop>() {}
+    assert_zeroize_on_drop::<zeroize::Zeroizing<Rand>>();
+
+    let rand = zeroize::Zeroizing::new(Rand::with_counter(100, KEY));
+    assert_eq!(rand.next_u64(), rand64(100, KEY));
+
+    //Feature doesn't add `Drop`, so `Rand` can still be dropped in `const` context
+    const fn key_of_dropped() -> u64 {
+        let rand = Rand::new(KEY);
+        rand.key()
+    }
+    assert_eq!(key_of_dropped(), KEY);
+}